@@ -0,0 +1,30 @@
+// Compares aggregate_partials against a plain sequential g1_add loop.
+//
+// Run with: cargo bench --bench aggregate
+
+use std::time::Instant;
+
+use brine_bn128_bls::bn254::g1_add;
+use brine_bn128_bls::utils::aggregate_partials;
+use brine_bn128_bls::{G1Point, PrivKey};
+
+fn main() {
+    for k in [8usize, 64, 256] {
+        let partials: Vec<G1Point> = (0..k)
+            .map(|_| G1Point::try_from(PrivKey::from_random()).unwrap())
+            .collect();
+
+        let start = Instant::now();
+        let sequential = partials[1..]
+            .iter()
+            .fold(partials[0], |acc, p| g1_add(acc, *p).unwrap());
+        let t_sequential = start.elapsed();
+
+        let start = Instant::now();
+        let batched = aggregate_partials(&partials).unwrap();
+        let t_batched = start.elapsed();
+
+        assert_eq!(batched.0, sequential.0);
+        println!("k = {k:>3}: sequential {t_sequential:>10.2?}, batched {t_batched:>10.2?}");
+    }
+}
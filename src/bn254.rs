@@ -0,0 +1,279 @@
+// Typed wrappers over the raw alt_bn128 syscalls.
+//
+// The syscalls take flat big-endian byte buffers:
+//   addition:       [P (64) | Q (64)]                 -> P + Q (64)
+//   multiplication: [P (64) | k (32)]                 -> k * P (64)
+//   pairing:        [P_1 (64) | Q_1 (128) | ... ]     -> 32 bytes, 1 if the product is one
+//
+// These helpers hide the buffer layouts behind G1Point / G2Point so custom protocols don't have
+// to build them by hand. The higher-level signing and verification functions are built on them.
+
+use dashu::integer::UBig;
+use solana_bn254::prelude::{alt_bn128_addition, alt_bn128_multiplication, alt_bn128_pairing};
+
+use crate::consts::MODULUS;
+use crate::errors::BLSError;
+use crate::g1::G1Point;
+use crate::g2::G2Point;
+
+/// Add two G1 points.
+/// Input:
+/// - a, b: G1 points (uncompressed 64 bytes)
+///
+/// Output:
+/// - a + b as a G1 point
+pub fn g1_add(a: G1Point, b: G1Point) -> Result<G1Point, BLSError> {
+    let mut input = [0u8; 128];
+    input[..64].copy_from_slice(&a.0);
+    input[64..].copy_from_slice(&b.0);
+
+    let out = alt_bn128_addition(&input).map_err(|_| BLSError::AltBN128AddError)?;
+    Ok(G1Point(
+        out.try_into().map_err(|_| BLSError::AltBN128AddError)?,
+    ))
+}
+
+/// Multiply a G1 point by a scalar.
+/// Input:
+/// - p: G1 point (uncompressed 64 bytes)
+/// - scalar: 32 byte big-endian scalar
+///
+/// Output:
+/// - scalar * p as a G1 point
+pub fn g1_mul(p: G1Point, scalar: &[u8; 32]) -> Result<G1Point, BLSError> {
+    let mut input = [0u8; 96];
+    input[..64].copy_from_slice(&p.0);
+    input[64..].copy_from_slice(scalar);
+
+    let out = alt_bn128_multiplication(&input).map_err(|_| BLSError::AltBN128MulError)?;
+    Ok(G1Point(
+        out.try_into().map_err(|_| BLSError::AltBN128MulError)?,
+    ))
+}
+
+/// Decides whether a raw 32 byte pairing syscall result counts as success.
+pub trait PairingPredicate {
+    fn accepts(&self, result: &[u8]) -> bool;
+}
+
+/// Whether a pairing syscall result is the big-endian integer 1, i.e. the product was one.
+/// The single place this comparison is spelled out; every check goes through it.
+pub(crate) fn pairing_is_one(result: &[u8]) -> bool {
+    result.len() == 32 && result[..31].iter().all(|&b| b == 0) && result[31] == 1
+}
+
+/// Default predicate: the result is the big-endian integer 1.
+pub struct EqualsOne;
+
+impl PairingPredicate for EqualsOne {
+    fn accepts(&self, result: &[u8]) -> bool {
+        pairing_is_one(result)
+    }
+}
+
+/// Run a multi-pairing over (G1, G2) pairs.
+/// Input:
+/// - pairs: list of (P_i, Q_i)
+///
+/// Output:
+/// - true if e(P_1, Q_1) * ... * e(P_n, Q_n) == 1, false otherwise
+///
+/// Notes:
+/// - An empty list is rejected, since the empty product is trivially one.
+pub fn pairing(pairs: &[(G1Point, G2Point)]) -> Result<bool, BLSError> {
+    pairing_check(pairs, &EqualsOne)
+}
+
+/// Run a multi-pairing and judge the syscall result with a custom predicate.
+/// Output:
+/// - predicate.accepts(result)
+pub fn pairing_check<P: PairingPredicate>(
+    pairs: &[(G1Point, G2Point)],
+    predicate: &P,
+) -> Result<bool, BLSError> {
+    if pairs.is_empty() {
+        return Err(BLSError::EmptyInput);
+    }
+
+    let mut input = vec![0u8; 192 * pairs.len()];
+    for (i, (p, q)) in pairs.iter().enumerate() {
+        let off = 192 * i;
+        input[off..off + 64].copy_from_slice(&p.0);
+        input[off + 64..off + 192].copy_from_slice(&q.0);
+    }
+
+    let r = alt_bn128_pairing(&input).map_err(|_| BLSError::AltBN128PairingError)?;
+    Ok(predicate.accepts(&r))
+}
+
+/// Run the pairing syscall on a caller-built input buffer.
+/// Input:
+/// - input: [P_1 (64) | Q_1 (128) | ... | P_n (64) | Q_n (128)]
+///
+/// Output:
+/// - true if the product of the pairings is one, false otherwise
+///
+/// Notes:
+/// - The thinnest wrapper over alt_bn128_pairing, for equations the typed helpers don't cover.
+/// - Empty input is Err(EmptyInput); any other length that isn't a multiple of 192 is
+///   Err(SerializationError).
+pub fn raw_pairing(input: &[u8]) -> Result<bool, BLSError> {
+    if input.is_empty() {
+        return Err(BLSError::EmptyInput);
+    }
+    if !input.len().is_multiple_of(192) {
+        return Err(BLSError::SerializationError);
+    }
+
+    let r = alt_bn128_pairing(input).map_err(|_| BLSError::AltBN128PairingError)?;
+    Ok(pairing_is_one(&r))
+}
+
+/// Negate a big-endian base field element: p - x (and 0 stays 0).
+pub(crate) fn fq_neg(x: &[u8]) -> [u8; 32] {
+    let x = UBig::from_be_bytes(x) % &MODULUS;
+    let mut out = [0u8; 32];
+    if x != UBig::ZERO {
+        let neg = (&MODULUS - x).to_be_bytes();
+        out[32 - neg.len()..].copy_from_slice(&neg);
+    }
+    out
+}
+
+/// Negate a G1 point by negating y. Works on-chain.
+pub(crate) fn g1_neg(p: &G1Point) -> G1Point {
+    let mut out = p.0;
+    out[32..].copy_from_slice(&fq_neg(&p.0[32..]));
+    G1Point(out)
+}
+
+/// Negate a G2 point by negating both Fq2 coefficients of y. Works on-chain.
+pub(crate) fn g2_neg(p: &G2Point) -> G2Point {
+    let mut out = p.0;
+    out[64..96].copy_from_slice(&fq_neg(&p.0[64..96]));
+    out[96..128].copy_from_slice(&fq_neg(&p.0[96..128]));
+    G2Point(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        g1_add, g1_mul, g2_neg, pairing, pairing_check, pairing_is_one, raw_pairing,
+        PairingPredicate,
+    };
+    use crate::consts::G2_MINUS_ONE;
+    use crate::g1::G1Point;
+    use crate::g2::G2Point;
+    use crate::hash::hash_to_curve;
+    use crate::privkey::PrivKey;
+
+    fn scalar(n: u8) -> [u8; 32] {
+        let mut s = [0u8; 32];
+        s[31] = n;
+        s
+    }
+
+    #[test]
+    fn g1_add_matches_doubling() {
+        let g = G1Point::try_from(PrivKey(scalar(1))).expect("g");
+        let two_g = G1Point::try_from(PrivKey(scalar(2))).expect("2g");
+        let sum = g1_add(g, g).expect("add");
+        assert_eq!(sum.0, two_g.0);
+    }
+
+    #[test]
+    fn g1_mul_matches_key_derivation() {
+        let sk = PrivKey::from_random();
+        let g = G1Point::try_from(PrivKey(scalar(1))).expect("g");
+        let expected = G1Point::try_from(PrivKey(sk.0)).expect("pk");
+        let product = g1_mul(g, &sk.0).expect("mul");
+        assert_eq!(product.0, expected.0);
+    }
+
+    #[test]
+    fn pairing_matches_verify() {
+        let sk = PrivKey::from_random();
+        let msg = b"bn254-pairing";
+        let sig = sk.sign(msg).expect("sign");
+        let pk = G2Point::try_from(&sk).expect("pk");
+        let h = hash_to_curve(msg).expect("hash");
+
+        assert!(pk.verify(&sig, msg).is_ok());
+        assert!(pairing(&[(h, pk), (sig, G2Point(G2_MINUS_ONE))]).expect("pairing"));
+
+        let wrong = sk.sign(b"other").expect("sign");
+        assert!(!pairing(&[(h, pk), (wrong, G2Point(G2_MINUS_ONE))]).expect("pairing"));
+        assert!(pairing(&[]).is_err());
+    }
+
+    #[test]
+    fn g2_neg_cancels_in_pairing() {
+        let g1 = G1Point::try_from(PrivKey(scalar(1))).expect("g1");
+        let pk = G2Point::try_from(&PrivKey::from_random()).expect("pk");
+
+        // e(G1, pk) * e(G1, -pk) == 1
+        assert!(pairing(&[(g1, pk), (g1, g2_neg(&pk))]).expect("pairing"));
+        assert!(!pairing(&[(g1, pk), (g1, pk)]).expect("pairing"));
+        assert_eq!(g2_neg(&g2_neg(&pk)).0, pk.0);
+    }
+
+    #[test]
+    fn pairing_check_custom_predicate() {
+        // Accepts any result that is not one, i.e. the inverse of EqualsOne
+        struct NotOne;
+        impl PairingPredicate for NotOne {
+            fn accepts(&self, result: &[u8]) -> bool {
+                result[31] != 1
+            }
+        }
+
+        let sk = PrivKey::from_random();
+        let msg = b"custom-predicate";
+        let sig = sk.sign(msg).expect("sign");
+        let pk = G2Point::try_from(&sk).expect("pk");
+        let h = hash_to_curve(msg).expect("hash");
+        let pairs = [(h, pk), (sig, G2Point(G2_MINUS_ONE))];
+
+        assert!(pairing(&pairs).expect("pairing"));
+        assert!(!pairing_check(&pairs, &NotOne).expect("pairing"));
+    }
+
+    #[test]
+    fn pairing_is_one_exact_match() {
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        assert!(pairing_is_one(&one));
+
+        assert!(!pairing_is_one(&[0u8; 32]));
+        let mut high = one;
+        high[0] = 1;
+        assert!(!pairing_is_one(&high));
+        // 1 in the wrong byte order, or with the wrong length
+        let mut little = [0u8; 32];
+        little[0] = 1;
+        assert!(!pairing_is_one(&little));
+        assert!(!pairing_is_one(&one[1..]));
+        assert!(!pairing_is_one(&[]));
+    }
+
+    #[test]
+    fn raw_pairing_known_valid_input() {
+        // e(H(m), pk) * e(sig, -G2) laid out by hand
+        let sk = PrivKey::from_random();
+        let msg = b"raw-pairing";
+        let sig = sk.sign(msg).expect("sign");
+        let pk = G2Point::try_from(&sk).expect("pk");
+
+        let mut input = Vec::with_capacity(384);
+        input.extend_from_slice(&hash_to_curve(msg).expect("hash").0);
+        input.extend_from_slice(&pk.0);
+        input.extend_from_slice(&sig.0);
+        input.extend_from_slice(&G2_MINUS_ONE);
+        assert!(raw_pairing(&input).expect("raw pairing"));
+
+        // Dropping the second pair leaves a product that isn't one
+        assert!(!raw_pairing(&input[..192]).expect("raw pairing"));
+        assert!(raw_pairing(&input[..191]).is_err());
+        assert!(raw_pairing(&[]).is_err());
+    }
+}
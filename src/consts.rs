@@ -23,21 +23,67 @@ pub static NORMALIZE_MODULUS: UBig = unsafe {
     ])
 };
 
+/// SCALAR_ORDER: The order r of G1/G2 (the scalar field modulus), big-endian.
+/// 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
+pub const SCALAR_ORDER: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// Estimated security level of BN254 in bits.
+/// The curve was designed for 128 bits, but the exTNFS attacks on its pairing target field
+/// bring the estimate to about 100.
+pub const SECURITY_BITS: u32 = 100;
+
+/// Human-readable notice of the security level, for surfacing in tooling and logs.
+#[must_use]
+pub fn security_notice() -> &'static str {
+    "BN254 (alt_bn128) provides about 100 bits of security, not 128. \
+     Do not use it where a 128-bit security target is required."
+}
+
+/// The G1 generator (1, 2), uncompressed in the syscall layout: x (32) || y (32), each
+/// big-endian. Same bytes as G1Point::generator().
+pub const G1_GENERATOR: [u8; 64] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x01,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x02,
+];
+
+/// The negated G1 generator (1, p - 2), in the same layout as G1_GENERATOR.
 pub const G1_MINUS_ONE: [u8; 64] = [
     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
     0x00, 0x00, 0x00, 0x01,
-    0xb0, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81,
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81,
     0x58, 0x5d, 0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16,
     0xd8, 0x7c, 0xfd, 0x45,
 ];
 
+/// The G2 generator, uncompressed in the syscall layout: x_c1 || x_c0 || y_c1 || y_c0, each a
+/// 32 byte big-endian base field element (EIP-197 order, imaginary part first).
+pub const G2_GENERATOR: [u8; 128] = [
+    0x19, 0x8e, 0x93, 0x93, 0x92, 0x0d, 0x48, 0x3a, 0x72, 0x60, 0xbf, 0xb7, 0x31, 0xfb, 0x5d, 0x25,
+    0xf1, 0xaa, 0x49, 0x33, 0x35, 0xa9, 0xe7, 0x12, 0x97, 0xe4, 0x85, 0xb7, 0xae, 0xf3, 0x12, 0xc2,
+    0x18, 0x00, 0xde, 0xef, 0x12, 0x1f, 0x1e, 0x76, 0x42, 0x6a, 0x00, 0x66, 0x5e, 0x5c, 0x44, 0x79,
+    0x67, 0x43, 0x22, 0xd4, 0xf7, 0x5e, 0xda, 0xdd, 0x46, 0xde, 0xbd, 0x5c, 0xd9, 0x92, 0xf6, 0xed,
+    0x09, 0x06, 0x89, 0xd0, 0x58, 0x5f, 0xf0, 0x75, 0xec, 0x9e, 0x99, 0xad, 0x69, 0x0c, 0x33, 0x95,
+    0xbc, 0x4b, 0x31, 0x33, 0x70, 0xb3, 0x8e, 0xf3, 0x55, 0xac, 0xda, 0xdc, 0xd1, 0x22, 0x97, 0x5b,
+    0x12, 0xc8, 0x5e, 0xa5, 0xdb, 0x8c, 0x6d, 0xeb, 0x4a, 0xab, 0x71, 0x80, 0x8d, 0xcb, 0x40, 0x8f,
+    0xe3, 0xd1, 0xe7, 0x69, 0x0c, 0x43, 0xd3, 0x7b, 0x4c, 0xe6, 0xcc, 0x01, 0x66, 0xfa, 0x7d, 0xaa,
+];
+
+/// The negated G2 generator, in the same layout as G2_GENERATOR. The right-hand G2 term of
+/// every e(H(m), pk) * e(sig, -G2) == 1 check.
 pub const G2_MINUS_ONE: [u8; 128] = [
     0x19, 0x8e, 0x93, 0x93, 0x92, 0x0d, 0x48, 0x3a, 0x72, 0x60, 0xbf, 0xb7, 0x31, 0xfb, 0x5d, 0x25,
     0xf1, 0xaa, 0x49, 0x33, 0x35, 0xa9, 0xe7, 0x12, 0x97, 0xe4, 0x85, 0xb7, 0xae, 0xf3, 0x12, 0xc2,
     0x18, 0x00, 0xde, 0xef, 0x12, 0x1f, 0x1e, 0x76, 0x42, 0x6a, 0x00, 0x66, 0x5e, 0x5c, 0x44, 0x79,
     0x67, 0x43, 0x22, 0xd4, 0xf7, 0x5e, 0xda, 0xdd, 0x46, 0xde, 0xbd, 0x5c, 0xd9, 0x92, 0xf6, 0xed,
-    0xa7, 0x5d, 0xc4, 0xa2, 0x88, 0xd1, 0xaf, 0xb3, 0xcb, 0xb1, 0xac, 0x09, 0x18, 0x75, 0x24, 0xc7,
+    0x27, 0x5d, 0xc4, 0xa2, 0x88, 0xd1, 0xaf, 0xb3, 0xcb, 0xb1, 0xac, 0x09, 0x18, 0x75, 0x24, 0xc7,
     0xdb, 0x36, 0x39, 0x5d, 0xf7, 0xbe, 0x3b, 0x99, 0xe6, 0x73, 0xb1, 0x3a, 0x07, 0x5a, 0x65, 0xec,
     0x1d, 0x9b, 0xef, 0xcd, 0x05, 0xa5, 0x32, 0x3e, 0x6d, 0xa4, 0xd4, 0x35, 0xf3, 0xb6, 0x17, 0xcd,
     0xb3, 0xaf, 0x83, 0x28, 0x5c, 0x2d, 0xf7, 0x11, 0xef, 0x39, 0xc0, 0x15, 0x71, 0x82, 0x7f, 0x9d,
@@ -45,9 +91,11 @@ pub const G2_MINUS_ONE: [u8; 128] = [
 
 #[cfg(all(test, not(target_os = "solana")))]
 mod tests {
-    use super::{G1_MINUS_ONE, G2_MINUS_ONE};
+    use super::{
+        security_notice, G1_GENERATOR, G1_MINUS_ONE, G2_GENERATOR, G2_MINUS_ONE, SECURITY_BITS,
+    };
     use ark_bn254::{G1Affine, G2Affine};
-    use ark_ec::AffineRepr;
+    use ark_ec::{AffineRepr, CurveGroup};
     use ark_serialize::CanonicalSerialize;
 
     #[test]
@@ -55,10 +103,12 @@ mod tests {
         // Compute negation of G1 generator
         let g1_gen = G1Affine::generator();
         let g1_neg = -g1_gen;
+        // Serialize the coordinates one at a time: serializing the whole point would set
+        // arkworks' y-sign flag in the top bit, which is not part of the syscall encoding
+        let (x, y) = g1_neg.xy().expect("affine");
         let mut computed_bytes = [0u8; 64];
-        g1_neg
-            .serialize_uncompressed(&mut computed_bytes[..])
-            .expect("Serialization failed");
+        x.serialize_uncompressed(&mut computed_bytes[..32]).expect("Serialization failed");
+        y.serialize_uncompressed(&mut computed_bytes[32..]).expect("Serialization failed");
 
         // Convert to big-endian by reversing each 32-byte block (x and y coordinates)
         computed_bytes[0..32].reverse();
@@ -76,10 +126,10 @@ mod tests {
         // Compute negation of G2 generator
         let g2_gen = G2Affine::generator();
         let g2_neg = -g2_gen;
+        let (x, y) = g2_neg.xy().expect("affine");
         let mut computed_bytes = [0u8; 128];
-        g2_neg
-            .serialize_uncompressed(&mut computed_bytes[..])
-            .expect("Serialization failed");
+        x.serialize_uncompressed(&mut computed_bytes[..64]).expect("Serialization failed");
+        y.serialize_uncompressed(&mut computed_bytes[64..]).expect("Serialization failed");
 
         // Convert to big-endian by reversing each 64-byte block (x and y coordinates)
         computed_bytes[0..64].reverse();
@@ -91,4 +141,46 @@ mod tests {
             "Computed G2_MINUS_ONE does not match static constant"
         );
     }
+
+    #[test]
+    fn generators_cancel_their_negations() {
+        use crate::bn254::g1_add;
+        use crate::g1::G1Point;
+        use crate::g2::G2Point;
+
+        assert!(G1Point(G1_GENERATOR) == G1Point::generator());
+        assert_eq!(G2Point(G2_GENERATOR).to_ark().expect("g2"), G2Affine::generator());
+        assert!(G1Point(G1_MINUS_ONE) == G1Point::neg_generator());
+        assert_eq!(G2Point(G2_MINUS_ONE), G2Point::neg_generator());
+
+        // G + (-G) is the point at infinity, the all-zero encoding
+        let sum = g1_add(G1Point(G1_GENERATOR), G1Point(G1_MINUS_ONE)).expect("g1 add");
+        assert!(sum.is_identity());
+        let g2_sum =
+            G2Point(G2_GENERATOR).to_ark().unwrap() + G2Point(G2_MINUS_ONE).to_ark().unwrap();
+        assert!(G2Point::from_ark(&g2_sum.into_affine()).expect("g2 sum").is_identity());
+    }
+
+    #[test]
+    fn generator_coordinates_are_canonical() {
+        use dashu::integer::UBig;
+
+        // Every coordinate must be a plain big-endian field element below p. The negated
+        // generators once carried arkworks' y-sign flag (0xb0 and 0xa7 top bytes instead of 0x30
+        // and 0x27), which the syscall masked but EIP-197 rejects
+        let consts: [&[u8]; 4] = [&G1_GENERATOR, &G1_MINUS_ONE, &G2_GENERATOR, &G2_MINUS_ONE];
+        for bytes in consts {
+            for coord in bytes.chunks_exact(32) {
+                assert!(UBig::from_be_bytes(coord) < super::MODULUS);
+            }
+        }
+        assert_eq!(G1_MINUS_ONE[32], 0x30);
+        assert_eq!(G2_MINUS_ONE[64], 0x27);
+    }
+
+    #[test]
+    fn security_level_is_exposed() {
+        assert_eq!(SECURITY_BITS, 100);
+        assert!(security_notice().contains("100 bits"));
+    }
 }
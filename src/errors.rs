@@ -1,3 +1,5 @@
+/// Errors returned by every fallible operation in the crate.
+/// Display gives a short human-readable message; std::error::Error is behind the std feature.
 #[derive(Debug, PartialEq, Eq)]
 pub enum BLSError {
     SecretKeyError,
@@ -12,4 +14,234 @@ pub enum BLSError {
     G1PointDecompressionError,
     G2PointCompressionError,
     G2PointDecompressionError,
+    ScalarOutOfRange,
+    EmptySignerSet,
+    IndexOutOfBounds,
+    DuplicateSigner,
+    PointAtInfinity,
+    InvalidPublicKey,
+    InvalidSignature,
+    EmptyInput,
+    InvalidLength { expected: usize, got: usize },
+    DuplicateMessage,
+    WeightOverflow,
+}
+
+impl core::fmt::Display for BLSError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            BLSError::SecretKeyError => "invalid secret key",
+            BLSError::AltBN128AddError => "alt_bn128 addition failed",
+            BLSError::AltBN128MulError => "alt_bn128 multiplication failed",
+            BLSError::AltBN128PairingError => "alt_bn128 pairing failed",
+            BLSError::HashToCurveError => "hash-to-curve failed after 255 attempts",
+            BLSError::BLSSigningError => "BLS signing failed",
+            BLSError::BLSVerificationError => "BLS signature verification failed",
+            BLSError::SerializationError => "malformed input or serialization failure",
+            BLSError::G1PointCompressionError => "G1 point compression failed",
+            BLSError::G1PointDecompressionError => "G1 point decompression failed",
+            BLSError::G2PointCompressionError => "G2 point compression failed",
+            BLSError::G2PointDecompressionError => "G2 point decompression failed",
+            BLSError::ScalarOutOfRange => "scalar is zero or not below the group order",
+            BLSError::EmptySignerSet => "empty signer set",
+            BLSError::IndexOutOfBounds => "committee index out of bounds",
+            BLSError::DuplicateSigner => "duplicate signer",
+            BLSError::PointAtInfinity => "unexpected point at infinity",
+            BLSError::InvalidPublicKey => "invalid public key",
+            BLSError::InvalidSignature => "invalid signature",
+            BLSError::EmptyInput => "empty input",
+            BLSError::InvalidLength { expected, got } => {
+                return write!(f, "invalid length: expected {} bytes, got {}", expected, got);
+            }
+            BLSError::DuplicateMessage => "duplicate message",
+            BLSError::WeightOverflow => "total signer weight overflows u64",
+        };
+        f.write_str(msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BLSError {}
+
+impl BLSError {
+    /// Stable numeric code for on-chain programs, to return as ProgramError::Custom(code).
+    ///
+    /// | code   | variant                   |
+    /// |--------|---------------------------|
+    /// | 0x1001 | BLSVerificationError      |
+    /// | 0x1002 | InvalidPublicKey          |
+    /// | 0x1003 | InvalidSignature          |
+    /// | 0x1004 | SecretKeyError            |
+    /// | 0x1005 | ScalarOutOfRange          |
+    /// | 0x1006 | HashToCurveError          |
+    /// | 0x1007 | BLSSigningError           |
+    /// | 0x1008 | SerializationError        |
+    /// | 0x1009 | G1PointCompressionError   |
+    /// | 0x100a | G1PointDecompressionError |
+    /// | 0x100b | G2PointCompressionError   |
+    /// | 0x100c | G2PointDecompressionError |
+    /// | 0x100d | AltBN128AddError          |
+    /// | 0x100e | AltBN128MulError          |
+    /// | 0x100f | AltBN128PairingError      |
+    /// | 0x1010 | EmptySignerSet            |
+    /// | 0x1011 | IndexOutOfBounds          |
+    /// | 0x1012 | DuplicateSigner           |
+    /// | 0x1013 | PointAtInfinity           |
+    /// | 0x1014 | EmptyInput                |
+    /// | 0x1015 | InvalidLength             |
+    /// | 0x1016 | DuplicateMessage          |
+    /// | 0x1017 | WeightOverflow            |
+    ///
+    /// Codes are never reused, so new variants get new numbers.
+    pub const fn custom_code(&self) -> u32 {
+        match self {
+            BLSError::BLSVerificationError => 0x1001,
+            BLSError::InvalidPublicKey => 0x1002,
+            BLSError::InvalidSignature => 0x1003,
+            BLSError::SecretKeyError => 0x1004,
+            BLSError::ScalarOutOfRange => 0x1005,
+            BLSError::HashToCurveError => 0x1006,
+            BLSError::BLSSigningError => 0x1007,
+            BLSError::SerializationError => 0x1008,
+            BLSError::G1PointCompressionError => 0x1009,
+            BLSError::G1PointDecompressionError => 0x100a,
+            BLSError::G2PointCompressionError => 0x100b,
+            BLSError::G2PointDecompressionError => 0x100c,
+            BLSError::AltBN128AddError => 0x100d,
+            BLSError::AltBN128MulError => 0x100e,
+            BLSError::AltBN128PairingError => 0x100f,
+            BLSError::EmptySignerSet => 0x1010,
+            BLSError::IndexOutOfBounds => 0x1011,
+            BLSError::DuplicateSigner => 0x1012,
+            BLSError::PointAtInfinity => 0x1013,
+            BLSError::EmptyInput => 0x1014,
+            BLSError::InvalidLength { .. } => 0x1015,
+            BLSError::DuplicateMessage => 0x1016,
+            BLSError::WeightOverflow => 0x1017,
+        }
+    }
+}
+
+/// `ProgramError::Custom(e.into())` works with both pinocchio and solana-program; with the
+/// `pinocchio` or `solana-program` feature a plain `?` converts directly.
+impl From<BLSError> for u32 {
+    fn from(e: BLSError) -> u32 {
+        e.custom_code()
+    }
+}
+
+#[cfg(feature = "pinocchio")]
+impl From<BLSError> for pinocchio::program_error::ProgramError {
+    fn from(e: BLSError) -> Self {
+        Self::Custom(e.custom_code())
+    }
+}
+
+#[cfg(feature = "solana-program")]
+impl From<BLSError> for solana_program::program_error::ProgramError {
+    fn from(e: BLSError) -> Self {
+        Self::Custom(e.custom_code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BLSError;
+
+    #[test]
+    fn display_messages() {
+        let cases = [
+            (BLSError::SecretKeyError, "secret key"),
+            (BLSError::AltBN128AddError, "addition"),
+            (BLSError::AltBN128MulError, "multiplication"),
+            (BLSError::AltBN128PairingError, "pairing"),
+            (BLSError::HashToCurveError, "hash-to-curve failed after 255 attempts"),
+            (BLSError::BLSSigningError, "signing"),
+            (BLSError::BLSVerificationError, "BLS signature verification failed"),
+            (BLSError::SerializationError, "malformed"),
+            (BLSError::G1PointCompressionError, "G1 point compression"),
+            (BLSError::G1PointDecompressionError, "G1 point decompression"),
+            (BLSError::G2PointCompressionError, "G2 point compression"),
+            (BLSError::G2PointDecompressionError, "G2 point decompression"),
+            (BLSError::ScalarOutOfRange, "group order"),
+            (BLSError::EmptySignerSet, "empty signer set"),
+            (BLSError::IndexOutOfBounds, "out of bounds"),
+            (BLSError::DuplicateSigner, "duplicate signer"),
+            (BLSError::PointAtInfinity, "infinity"),
+            (BLSError::InvalidPublicKey, "public key"),
+            (BLSError::InvalidSignature, "signature"),
+            (BLSError::EmptyInput, "empty input"),
+            (BLSError::InvalidLength { expected: 64, got: 63 }, "expected 64 bytes, got 63"),
+            (BLSError::DuplicateMessage, "duplicate message"),
+            (BLSError::WeightOverflow, "overflows"),
+        ];
+        for (e, phrase) in cases {
+            assert!(e.to_string().contains(phrase), "{:?}: {}", e, e);
+        }
+    }
+
+    #[test]
+    fn custom_codes_are_distinct_and_nonzero() {
+        let all = [
+            BLSError::SecretKeyError,
+            BLSError::AltBN128AddError,
+            BLSError::AltBN128MulError,
+            BLSError::AltBN128PairingError,
+            BLSError::HashToCurveError,
+            BLSError::BLSSigningError,
+            BLSError::BLSVerificationError,
+            BLSError::SerializationError,
+            BLSError::G1PointCompressionError,
+            BLSError::G1PointDecompressionError,
+            BLSError::G2PointCompressionError,
+            BLSError::G2PointDecompressionError,
+            BLSError::ScalarOutOfRange,
+            BLSError::EmptySignerSet,
+            BLSError::IndexOutOfBounds,
+            BLSError::DuplicateSigner,
+            BLSError::PointAtInfinity,
+            BLSError::InvalidPublicKey,
+            BLSError::InvalidSignature,
+            BLSError::EmptyInput,
+            BLSError::InvalidLength { expected: 32, got: 0 },
+            BLSError::DuplicateMessage,
+            BLSError::WeightOverflow,
+        ];
+        let mut codes: Vec<u32> = all.iter().map(BLSError::custom_code).collect();
+        assert!(codes.iter().all(|&c| c != 0));
+        assert_eq!(u32::from(BLSError::BLSVerificationError), 0x1001);
+        assert_eq!(u32::from(BLSError::InvalidPublicKey), 0x1002);
+        codes.sort_unstable();
+        codes.dedup();
+        assert_eq!(codes.len(), all.len());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn boxes_as_std_error() {
+        fn fails() -> Result<(), Box<dyn std::error::Error>> {
+            Err(BLSError::DuplicateSigner)?
+        }
+        assert_eq!(fails().unwrap_err().to_string(), "duplicate signer");
+    }
+
+    #[cfg(feature = "pinocchio")]
+    #[test]
+    fn converts_to_pinocchio_program_error() {
+        use pinocchio::program_error::ProgramError;
+        fn fails() -> Result<(), ProgramError> {
+            Err(BLSError::BLSVerificationError)?
+        }
+        assert_eq!(fails().unwrap_err(), ProgramError::Custom(0x1001));
+    }
+
+    #[cfg(feature = "solana-program")]
+    #[test]
+    fn converts_to_solana_program_error() {
+        use solana_program::program_error::ProgramError;
+        fn fails() -> Result<(), ProgramError> {
+            Err(BLSError::BLSVerificationError)?
+        }
+        assert_eq!(fails().unwrap_err(), ProgramError::Custom(0x1001));
+    }
 }
@@ -1,19 +1,373 @@
-use core::ops::Add;
-use num::CheckedAdd;
+#[cfg(not(target_os = "solana"))]
+use ark_ec::{AffineRepr, CurveGroup};
+#[cfg(not(target_os = "solana"))]
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use core::fmt::Write as _;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+use dashu::integer::UBig;
+use num::{CheckedAdd, CheckedSub};
 use solana_bn254::{
     compression::prelude::{alt_bn128_g1_compress, alt_bn128_g1_decompress},
-    prelude::{alt_bn128_addition, alt_bn128_multiplication},
+    prelude::alt_bn128_multiplication,
 };
 
+use crate::bn254::{g1_add, g1_mul, g1_neg};
+use crate::consts::{G1_GENERATOR, MODULUS, SCALAR_ORDER};
 use crate::errors::BLSError;
-use crate::privkey::PrivKey;
+use crate::hash::hash_to_curve;
+use crate::privkey::{ct_eq_bytes, PrivKey};
 
-#[derive(Clone)]
+/// Equality and hashing are bit-for-bit over the encoding, not over the curve point. Canonical
+/// encodings from this crate compare as expected, but a non-reduced coordinate or a compressed
+/// vs uncompressed form of the same point won't be equal unless normalized first.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct G1Point(pub [u8; 64]);
 
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct G1CompressedPoint(pub [u8; 32]);
 
+impl AsRef<[u8]> for G1Point {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for G1Point {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for G1CompressedPoint {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for G1CompressedPoint {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// Write `0x<lowercase hex>` through core::fmt, so it works on-chain without a hex crate.
+pub(crate) fn write_hex(f: &mut core::fmt::Formatter<'_>, bytes: &[u8]) -> core::fmt::Result {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    f.write_str("0x")?;
+    for b in bytes {
+        f.write_char(HEX[(b >> 4) as usize] as char)?;
+        f.write_char(HEX[(b & 0x0f) as usize] as char)?;
+    }
+    Ok(())
+}
+
+/// Write `Name(0x<lowercase hex>)` for Debug impls.
+pub(crate) fn fmt_hex(
+    f: &mut core::fmt::Formatter<'_>,
+    name: &str,
+    bytes: &[u8],
+) -> core::fmt::Result {
+    write!(f, "{}(", name)?;
+    write_hex(f, bytes)?;
+    f.write_str(")")
+}
+
+impl core::fmt::Debug for G1Point {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_hex(f, "G1Point", &self.0)
+    }
+}
+
+impl core::fmt::Debug for G1CompressedPoint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_hex(f, "G1CompressedPoint", &self.0)
+    }
+}
+
+impl core::fmt::Display for G1CompressedPoint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_hex(f, &self.0)
+    }
+}
+
+impl G1Point {
+    /// The point at infinity, in the all-zero encoding the syscalls use for it.
+    pub const IDENTITY: G1Point = G1Point([0u8; 64]);
+
+    /// Whether this is the point at infinity (all-zero bytes).
+    pub fn is_identity(&self) -> bool {
+        self.0 == [0u8; 64]
+    }
+
+    /// The G1 generator (1, 2).
+    pub const fn generator() -> G1Point {
+        G1Point(G1_GENERATOR)
+    }
+
+    /// The negated G1 generator (1, p - 2).
+    pub fn neg_generator() -> G1Point {
+        g1_neg(&G1Point::generator())
+    }
+
+    /// Build a G1 point from big-endian affine coordinates.
+    /// Input:
+    /// - x, y: 32 byte big-endian field elements
+    ///
+    /// Output:
+    /// - The point in the 64 byte syscall layout [x | y]
+    ///
+    /// Notes:
+    /// - Rejects coordinates that are not reduced mod p or that do not satisfy y^2 = x^3 + 3.
+    pub fn from_affine_coords(x: &[u8; 32], y: &[u8; 32]) -> Result<G1Point, BLSError> {
+        if !is_on_curve_coords(x, y) {
+            return Err(BLSError::SerializationError);
+        }
+
+        let mut p = [0u8; 64];
+        p[..32].copy_from_slice(x);
+        p[32..].copy_from_slice(y);
+        Ok(G1Point(p))
+    }
+
+    /// Check that the bytes are an affine point on y^2 = x^3 + 3.
+    /// Output:
+    /// - true if both coordinates are reduced mod p and satisfy the curve equation
+    ///
+    /// Notes:
+    /// - The all-zero identity encoding is not an affine point and returns false; test for it
+    ///   separately where the identity is acceptable.
+    /// - Runs on-chain with big-integer arithmetic, so untrusted bytes can be checked before they
+    ///   reach a syscall that would otherwise fail with an opaque error.
+    pub fn is_on_curve(&self) -> bool {
+        let (x, y) = self.0.split_at(32);
+        is_on_curve_coords(x.try_into().unwrap(), y.try_into().unwrap())
+    }
+
+    /// Check that this point lies in the prime-order G1 subgroup.
+    /// Notes:
+    /// - G1 on BN254 has cofactor 1, so every curve point is in the subgroup and this is
+    ///   is_on_curve with the identity also accepted.
+    pub fn is_in_subgroup(&self) -> bool {
+        self.is_identity() || self.is_on_curve()
+    }
+
+    /// Negate this point: (x, y) -> (x, p - y).
+    /// Output:
+    /// - -self, with the identity mapping to itself
+    ///
+    /// Notes:
+    /// - Works on the raw bytes, so it runs on-chain without a syscall. Use it to move a term to
+    ///   the other side of a custom pairing equation, e.g. e(A, B) == e(C, D) as
+    ///   e(A, B) * e(-C, D) == 1.
+    /// - A y coordinate that isn't reduced mod p is rejected with SerializationError rather than
+    ///   silently reduced.
+    pub fn negate(&self) -> Result<G1Point, BLSError> {
+        if UBig::from_be_bytes(&self.0[32..]) >= MODULUS {
+            return Err(BLSError::SerializationError);
+        }
+        Ok(g1_neg(self))
+    }
+
+    /// Multiply this point by a scalar.
+    /// Input:
+    /// - scalar: 32 byte big-endian scalar in 0..r
+    ///
+    /// Output:
+    /// - scalar * self, via the multiplication syscall
+    ///
+    /// Notes:
+    /// - Scalars at or above r are rejected with Err(ScalarOutOfRange) rather than reduced, so
+    ///   two different byte strings can't silently give the same product.
+    pub fn scalar_mul(&self, scalar: &[u8; 32]) -> Result<G1Point, BLSError> {
+        if *scalar >= SCALAR_ORDER {
+            return Err(BLSError::ScalarOutOfRange);
+        }
+        g1_mul(*self, scalar)
+    }
+
+    /// Multi-scalar multiplication: sum(scalars[i] * points[i]).
+    /// Input:
+    /// - points: G1 points
+    /// - scalars: 32 byte big-endian scalars in 0..r, one per point
+    ///
+    /// Output:
+    /// - The weighted sum, e.g. for weighted threshold aggregation
+    ///
+    /// Notes:
+    /// - Mismatched lengths are rejected with InvalidLength and empty input with
+    ///   EmptySignerSet, as for summing points.
+    /// - One multiplication and one addition syscall per point; there is no batched MSM syscall.
+    pub fn msm(points: &[G1Point], scalars: &[[u8; 32]]) -> Result<G1Point, BLSError> {
+        if points.len() != scalars.len() {
+            return Err(BLSError::InvalidLength { expected: points.len(), got: scalars.len() });
+        }
+        if points.is_empty() {
+            return Err(BLSError::EmptySignerSet);
+        }
+
+        let mut acc = points[0].scalar_mul(&scalars[0])?;
+        for (p, k) in points.iter().zip(scalars).skip(1) {
+            acc = g1_add(acc, p.scalar_mul(k)?)?;
+        }
+        Ok(acc)
+    }
+
+    /// Pedersen-style commitment to this point (typically a partial signature).
+    /// Input:
+    /// - blinding: 32 byte big-endian blinding scalar r
+    ///
+    /// Output:
+    /// - C = self + r * H, where H is the second generator (see pedersen_h)
+    ///
+    /// Notes:
+    /// - Hiding as long as r is random and kept secret until the reveal.
+    /// - Not binding: the committed value is a point, not a scalar, so the committer can open C
+    ///   to self + k * H with blinding r - k for any k. Check the opened point independently
+    ///   (e.g. verify it as a partial signature) rather than relying on the commitment.
+    pub fn commit(&self, blinding: &[u8; 32]) -> Result<G1Point, BLSError> {
+        g1_add(*self, g1_mul(pedersen_h()?, blinding)?)
+    }
+
+    /// Check that `commitment` opens to this point with the given blinding.
+    /// Output:
+    /// - Ok if commitment == self + r * H, Err(BLSVerificationError) otherwise
+    pub fn verify_commitment(
+        &self,
+        commitment: &G1Point,
+        blinding: &[u8; 32],
+    ) -> Result<(), BLSError> {
+        if self.commit(blinding)?.0 == commitment.0 {
+            Ok(())
+        } else {
+            Err(BLSError::BLSVerificationError)
+        }
+    }
+}
+
+/// Header of the 48 byte framed form of a G1CompressedPoint (see G1CompressedPoint::to_framed).
+///
+/// The first byte is 0x80 so the blob reads as "compressed, not infinity" to interfaces that
+/// only look at the BLS12-381 flag bits. The rest is an ASCII tag plus zero padding.
+pub const G1_FRAME_TAG: [u8; 16] = *b"\x80BN254-G1-FRAME\0";
+
+/// Same comparison as the inherent ct_eq, returned as a subtle::Choice.
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeEq for G1CompressedPoint {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0[..].ct_eq(&other.0[..])
+    }
+}
+
+impl G1CompressedPoint {
+    /// Compare two compressed points in constant time, e.g. when deduplicating keys where the
+    /// position of the first differing byte shouldn't be observable. Same notes as PrivKey::ct_eq.
+    pub fn ct_eq(&self, other: &G1CompressedPoint) -> bool {
+        ct_eq_bytes(&self.0, &other.0)
+    }
+
+    /// Frame the 32 byte compressed point as a 48 byte blob for APIs sized for BLS12-381.
+    /// Output:
+    /// - G1_FRAME_TAG (16) || compressed point (32)
+    ///
+    /// Notes:
+    /// - This is an interop shim, not a curve change: the blob is still a BN254 point and only
+    ///   this crate (via from_framed) can make sense of it.
+    pub fn to_framed(&self) -> [u8; 48] {
+        let mut out = [0u8; 48];
+        out[..16].copy_from_slice(&G1_FRAME_TAG);
+        out[16..].copy_from_slice(&self.0);
+        out
+    }
+
+    /// Strip the framing added by to_framed.
+    /// Output:
+    /// - The compressed point, or Err(SerializationError) if the header is not G1_FRAME_TAG
+    ///
+    /// Notes:
+    /// - Only the framing is checked; decompress the result to validate the point itself.
+    pub fn from_framed(bytes: &[u8; 48]) -> Result<G1CompressedPoint, BLSError> {
+        if bytes[..16] != G1_FRAME_TAG {
+            return Err(BLSError::SerializationError);
+        }
+        Ok(G1CompressedPoint(bytes[16..].try_into().unwrap()))
+    }
+}
+
+#[cfg(not(target_os = "solana"))]
+impl G1Point {
+    /// Convert to an arkworks affine point (all-zero bytes are the identity).
+    pub(crate) fn to_ark(self) -> Result<ark_bn254::G1Affine, BLSError> {
+        if self.0 == [0u8; 64] {
+            return Ok(ark_bn254::G1Affine::identity());
+        }
+        let mut bytes = self.0;
+        bytes[..32].reverse();
+        bytes[32..].reverse();
+        ark_bn254::G1Affine::deserialize_uncompressed(&bytes[..])
+            .map_err(|_| BLSError::SerializationError)
+    }
+
+    /// Convert from an arkworks affine point (the identity becomes all-zero bytes).
+    pub(crate) fn from_ark(p: &ark_bn254::G1Affine) -> Result<G1Point, BLSError> {
+        let mut bytes = [0u8; 64];
+        if let Some((x, y)) = p.xy() {
+            x.serialize_uncompressed(&mut bytes[..32])
+                .map_err(|_| BLSError::SerializationError)?;
+            y.serialize_uncompressed(&mut bytes[32..])
+                .map_err(|_| BLSError::SerializationError)?;
+            bytes[..32].reverse();
+            bytes[32..].reverse();
+        }
+        Ok(G1Point(bytes))
+    }
+}
+
+/// Convert to an arkworks affine point, for off-chain computation (ark feature).
+/// Err(SerializationError) if the bytes aren't a valid G1 point; all-zero bytes are the identity.
+#[cfg(all(feature = "ark", not(target_os = "solana")))]
+impl TryFrom<&G1Point> for ark_bn254::G1Affine {
+    type Error = BLSError;
+
+    fn try_from(value: &G1Point) -> Result<Self, Self::Error> {
+        value.to_ark()
+    }
+}
+
+/// Convert from an arkworks affine point (ark feature); the identity becomes all-zero bytes.
+#[cfg(all(feature = "ark", not(target_os = "solana")))]
+impl TryFrom<&ark_bn254::G1Affine> for G1Point {
+    type Error = BLSError;
+
+    fn try_from(value: &ark_bn254::G1Affine) -> Result<Self, Self::Error> {
+        G1Point::from_ark(value)
+    }
+}
+
+/// Seed for the second Pedersen generator.
+pub const PEDERSEN_H_SEED: &[u8] = b"BLS-BN254-PEDERSEN-H";
+
+/// Second G1 generator for commitments.
+///
+/// H = hash_to_curve(PEDERSEN_H_SEED). It is a nothing-up-my-sleeve point: it comes out of the
+/// message hash, so its discrete log with respect to the generator is unknown to everyone.
+pub fn pedersen_h() -> Result<G1Point, BLSError> {
+    hash_to_curve(PEDERSEN_H_SEED)
+}
+
+/// Check y^2 = x^3 + 3 (mod p) for big-endian coordinates reduced mod p.
+fn is_on_curve_coords(x: &[u8; 32], y: &[u8; 32]) -> bool {
+    let x = UBig::from_be_bytes(x);
+    let y = UBig::from_be_bytes(y);
+    if x >= MODULUS || y >= MODULUS {
+        return false;
+    }
+
+    let lhs = (&y * &y) % &MODULUS;
+    let rhs = (&x * &x * &x + UBig::from(3u8)) % &MODULUS;
+    lhs == rhs
+}
+
 impl Add for G1Point {
     type Output = G1Point;
 
@@ -24,22 +378,80 @@ impl Add for G1Point {
 
 impl CheckedAdd for G1Point {
     fn checked_add(&self, rhs: &Self) -> Option<Self> {
-        let mut combined_input = [0u8; 128];
+        g1_add(*self, *rhs).ok()
+    }
+}
 
-        unsafe {
-            *(combined_input.as_mut_ptr() as *mut [u8; 64]) = self.0;
-            *(combined_input.as_mut_ptr().add(64) as *mut [u8; 64]) = rhs.0;
-        }
+/// Sum points with `iter.sum::<Result<G1Point, BLSError>>()`.
+/// An empty iterator is Err(EmptySignerSet), so a zero-signer aggregate can't slip through.
+/// Off-chain the sum is accumulated in projective coordinates and normalized once; on-chain
+/// it is one addition syscall per extra point.
+impl Sum<G1Point> for Result<G1Point, BLSError> {
+    fn sum<I: Iterator<Item = G1Point>>(iter: I) -> Self {
+        sum_points(iter)
+    }
+}
+
+impl<'a> Sum<&'a G1Point> for Result<G1Point, BLSError> {
+    fn sum<I: Iterator<Item = &'a G1Point>>(iter: I) -> Self {
+        sum_points(iter.copied())
+    }
+}
+
+#[cfg(not(target_os = "solana"))]
+fn sum_points<I: Iterator<Item = G1Point>>(mut iter: I) -> Result<G1Point, BLSError> {
+    let first = iter.next().ok_or(BLSError::EmptySignerSet)?;
+    let mut acc: ark_bn254::G1Projective = first.to_ark()?.into();
+    for p in iter {
+        acc += p.to_ark()?;
+    }
+    G1Point::from_ark(&acc.into_affine())
+}
+
+#[cfg(target_os = "solana")]
+fn sum_points<I: Iterator<Item = G1Point>>(mut iter: I) -> Result<G1Point, BLSError> {
+    let mut acc = iter.next().ok_or(BLSError::EmptySignerSet)?;
+    for p in iter {
+        acc = g1_add(acc, p)?;
+    }
+    Ok(acc)
+}
+
+impl AddAssign for G1Point {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+/// a - b = a + (-b), with the negation done on the bytes so it works on-chain.
+impl Sub for G1Point {
+    type Output = G1Point;
 
-        let result = (|| -> Result<Self, BLSError> {
-            let result =
-                alt_bn128_addition(&combined_input).map_err(|_| BLSError::AltBN128AddError)?;
-            Ok(G1Point(
-                result.try_into().map_err(|_| BLSError::AltBN128AddError)?,
-            ))
-        })();
+    fn sub(self, rhs: Self) -> G1Point {
+        self.checked_sub(&rhs).expect("G1Point subtraction failed")
+    }
+}
 
-        result.ok()
+/// Fallible subtraction, e.g. to take a revoked signer's partial back out of an aggregate.
+/// Only needs the addition syscall, so it works on-chain.
+impl CheckedSub for G1Point {
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        g1_add(*self, g1_neg(rhs)).ok()
+    }
+}
+
+/// Negate y mod p on the raw bytes, so it works on-chain. The identity negates to itself.
+impl Neg for G1Point {
+    type Output = G1Point;
+
+    fn neg(self) -> G1Point {
+        g1_neg(&self)
+    }
+}
+
+impl SubAssign for G1Point {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
     }
 }
 
@@ -70,28 +482,13 @@ impl TryFrom<PrivKey> for G1CompressedPoint {
     }
 }
 
+/// Derives sk * G1. This is a min_pk-style public key; the canonical public key for
+/// PrivKey::sign is PrivKey::pubkey_g2.
 impl TryFrom<PrivKey> for G1Point {
     type Error = BLSError;
 
     fn try_from(value: PrivKey) -> Result<Self, Self::Error> {
-        let input = [
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
-            value.0[0],  value.0[1],  value.0[2],  value.0[3],  value.0[4],  value.0[5],  value.0[6],
-            value.0[7],  value.0[8],  value.0[9],  value.0[10], value.0[11], value.0[12], value.0[13],
-            value.0[14], value.0[15], value.0[16], value.0[17], value.0[18], value.0[19],
-            value.0[20], value.0[21], value.0[22], value.0[23], value.0[24], value.0[25],
-            value.0[26], value.0[27], value.0[28], value.0[29], value.0[30], value.0[31],
-        ];
-
-        let mut g1_sol_uncompressed = [0; 64];
-        g1_sol_uncompressed.clone_from_slice(
-            &alt_bn128_multiplication(&input).map_err(|_| BLSError::SecretKeyError)?,
-        );
-        Ok(G1Point(g1_sol_uncompressed))
+        g1_mul(G1Point::generator(), &value.0).map_err(|_| BLSError::SecretKeyError)
     }
 }
 
@@ -115,9 +512,127 @@ impl TryFrom<&G1CompressedPoint> for G1Point {
     }
 }
 
+impl TryFrom<&[u8]> for G1Point {
+    type Error = BLSError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = value
+            .try_into()
+            .map_err(|_| BLSError::InvalidLength { expected: 64, got: value.len() })?;
+        Ok(G1Point(bytes))
+    }
+}
+
+impl TryFrom<&[u8]> for G1CompressedPoint {
+    type Error = BLSError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = value
+            .try_into()
+            .map_err(|_| BLSError::InvalidLength { expected: 32, got: value.len() })?;
+        Ok(G1CompressedPoint(bytes))
+    }
+}
+
+/// Serde encoding shared by the point types: a lowercase hex string in human-readable
+/// formats (e.g. JSON) and raw bytes in binary formats (e.g. bincode).
+#[cfg(feature = "serde")]
+pub(crate) mod serde_hex {
+    use core::fmt;
+    use serde::de::{Error, SeqAccess, Visitor};
+    use serde::{Deserializer, Serializer};
+
+    pub(crate) fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+            serializer.serialize_str(&hex)
+        } else {
+            serializer.serialize_bytes(bytes)
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(BytesVisitor::<N>)
+        } else {
+            deserializer.deserialize_bytes(BytesVisitor::<N>)
+        }
+    }
+
+    struct BytesVisitor<const N: usize>;
+
+    impl<'de, const N: usize> Visitor<'de> for BytesVisitor<N> {
+        type Value = [u8; N];
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{} bytes or a {} character hex string", N, 2 * N)
+        }
+
+        fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+            if v.len() != 2 * N {
+                return Err(E::invalid_length(v.len(), &self));
+            }
+            if !v.bytes().all(|c| c.is_ascii_hexdigit()) {
+                return Err(E::custom("malformed hex in point encoding"));
+            }
+            let mut out = [0u8; N];
+            for (i, b) in out.iter_mut().enumerate() {
+                *b = u8::from_str_radix(&v[2 * i..2 * i + 2], 16).map_err(E::custom)?;
+            }
+            Ok(out)
+        }
+
+        fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+            v.try_into().map_err(|_| E::invalid_length(v.len(), &self))
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut out = [0u8; N];
+            for (i, b) in out.iter_mut().enumerate() {
+                *b = seq.next_element()?.ok_or_else(|| A::Error::invalid_length(i, &self))?;
+            }
+            if seq.next_element::<u8>()?.is_some() {
+                return Err(A::Error::invalid_length(N + 1, &self));
+            }
+            Ok(out)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for G1Point {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_hex::serialize(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for G1Point {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde_hex::deserialize(deserializer).map(G1Point)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for G1CompressedPoint {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_hex::serialize(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for G1CompressedPoint {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde_hex::deserialize(deserializer).map(G1CompressedPoint)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{G1CompressedPoint, G1Point};
+    use crate::bn254::g1_mul;
     use crate::privkey::PrivKey;
 
     #[test]
@@ -137,7 +652,7 @@ mod tests {
     fn g1_compress_decompress_idempotent() {
         let sk = PrivKey::from_random();
         let pk_uncompressed = G1Point::try_from(sk).expect("g1 from sk");
-        let pk_compressed = G1CompressedPoint::try_from(pk_uncompressed.clone()).expect("compress");
+        let pk_compressed = G1CompressedPoint::try_from(pk_uncompressed).expect("compress");
         let roundtrip = G1CompressedPoint::try_from(
             G1Point::try_from(&pk_compressed).expect("decompress again")
         ).expect("recompress");
@@ -152,13 +667,343 @@ mod tests {
         let c = G1Point::try_from(PrivKey::from_random()).expect("c");
 
         // Commutative: a + b = b + a
-        let ab = a.clone() + b.clone();
-        let ba = b.clone() + a.clone();
+        let ab = a + b;
+        let ba = b + a;
         assert_eq!(ab.0, ba.0);
 
         // Associative: (a + b) + c = a + (b + c)
-        let lhs = (a.clone() + b.clone()) + c.clone();
+        let lhs = (a + b) + c;
         let rhs = a + (b + c);
         assert_eq!(lhs.0, rhs.0);
     }
+
+    #[test]
+    fn g1_from_affine_coords_roundtrip() {
+        // The generator is (1, 2)
+        let mut x = [0u8; 32];
+        let mut y = [0u8; 32];
+        x[31] = 1;
+        y[31] = 2;
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        let g = G1Point::from_affine_coords(&x, &y).expect("generator");
+        assert_eq!(g.0, G1Point::try_from(PrivKey(one)).expect("g1 from sk").0);
+
+        // Split a random point into coordinates and rebuild it
+        let p = G1Point::try_from(PrivKey::from_random()).expect("p");
+        let px: [u8; 32] = p.0[..32].try_into().unwrap();
+        let py: [u8; 32] = p.0[32..].try_into().unwrap();
+        let rt = G1Point::from_affine_coords(&px, &py).expect("rebuild");
+        assert_eq!(rt.0, p.0);
+
+        // (1, 3) is not on the curve
+        y[31] = 3;
+        assert!(G1Point::from_affine_coords(&x, &y).is_err());
+    }
+
+    #[test]
+    fn g1_commitment_opens() {
+        let sk = PrivKey::from_random();
+        let partial = sk.sign(b"commit-reveal").expect("sign");
+        let blinding = PrivKey::from_random().0;
+
+        let c = partial.commit(&blinding).expect("commit");
+        partial.verify_commitment(&c, &blinding).expect("open");
+
+        // Wrong blinding or wrong partial must not open the commitment
+        let other_blinding = PrivKey::from_random().0;
+        assert!(partial.verify_commitment(&c, &other_blinding).is_err());
+        let other = sk.sign(b"other").expect("sign");
+        assert!(other.verify_commitment(&c, &blinding).is_err());
+
+        // The commitment is not binding: shifting one H from the blinding into the point opens
+        // the same C to a different point
+        let mut two = [0u8; 32];
+        two[31] = 2;
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        let c = partial.commit(&two).expect("commit");
+        let shifted = partial + super::pedersen_h().expect("h");
+        shifted.verify_commitment(&c, &one).expect("equivocal opening");
+    }
+
+    #[test]
+    fn g1_framed_roundtrip_and_rejects_bad_header() {
+        let pk = G1CompressedPoint::try_from(PrivKey::from_random()).expect("g1c from sk");
+        let framed = pk.to_framed();
+        assert_eq!(framed[0] & 0x80, 0x80);
+        assert_eq!(G1CompressedPoint::from_framed(&framed).expect("unframe").0, pk.0);
+
+        // Any change to the header is rejected
+        let mut bad = framed;
+        bad[3] ^= 1;
+        assert!(G1CompressedPoint::from_framed(&bad).is_err());
+        let mut zero_padded = [0u8; 48];
+        zero_padded[16..].copy_from_slice(&pk.0);
+        assert!(G1CompressedPoint::from_framed(&zero_padded).is_err());
+    }
+
+    #[test]
+    fn g1_equality_compares_bytes() {
+        let sk = PrivKey::from_random();
+        let a = G1Point::try_from(PrivKey(sk.0)).expect("a");
+        let b = G1Point::try_from(PrivKey(sk.0)).expect("b");
+        assert!(a == b);
+        assert!(a != G1Point::try_from(PrivKey::from_random()).expect("other"));
+
+        let c = G1CompressedPoint::try_from(a).expect("compress");
+        assert!(c == G1CompressedPoint::try_from(PrivKey(sk.0)).expect("compressed from sk"));
+        assert!(G1Point::try_from(&c).expect("decompress") == a);
+    }
+
+    #[test]
+    fn g1_debug_is_hex() {
+        let mut x = [0u8; 32];
+        let mut y = [0u8; 32];
+        x[31] = 1;
+        y[31] = 2;
+        let g = G1Point::from_affine_coords(&x, &y).expect("generator");
+        let expected = format!("G1Point(0x{}01{}02)", "00".repeat(31), "00".repeat(31));
+        assert_eq!(format!("{:?}", g), expected);
+
+        // The compressed form of a point prints as the hex of its compressed bytes
+        let p = G1Point::try_from(PrivKey::from_random()).expect("p");
+        let c = G1CompressedPoint::try_from(p).expect("compress");
+        let hex: String = c.0.iter().map(|b| format!("{:02x}", b)).collect();
+        assert_eq!(format!("{:?}", c), format!("G1CompressedPoint(0x{})", hex));
+        assert_eq!(c.to_string(), format!("0x{}", hex));
+        // Past the flag bits in the first byte, the compressed bytes are x
+        assert_eq!(&format!("{:?}", p)[12..74], &hex[2..]);
+    }
+
+    #[test]
+    fn g1_as_ref_lengths() {
+        let mut p = G1Point::try_from(PrivKey::from_random()).expect("p");
+        let mut c = G1CompressedPoint::try_from(p).expect("compress");
+        assert_eq!(p.as_ref().len(), 64);
+        assert_eq!(c.as_ref().len(), 32);
+
+        // AsMut writes through to the inner bytes
+        p.as_mut()[0] = 0xaa;
+        c.as_mut().fill(0);
+        assert_eq!(p.0[0], 0xaa);
+        assert_eq!(c.0, [0u8; 32]);
+    }
+
+    #[test]
+    fn g1_try_from_slice_checks_length() {
+        let p = G1Point::try_from(PrivKey::from_random()).expect("p");
+        assert!(G1Point::try_from(&p.0[..]).expect("exact") == p);
+        let c = G1CompressedPoint::try_from(p).expect("compress");
+        assert!(G1CompressedPoint::try_from(&c.0[..]).expect("exact") == c);
+
+        use crate::errors::BLSError;
+
+        let long = [0u8; 65];
+        for len in [0, 63, 65] {
+            let err = G1Point::try_from(&long[..len]).unwrap_err();
+            assert_eq!(err, BLSError::InvalidLength { expected: 64, got: len });
+        }
+        for len in [0, 31, 33] {
+            let err = G1CompressedPoint::try_from(&long[..len]).unwrap_err();
+            assert_eq!(err, BLSError::InvalidLength { expected: 32, got: len });
+        }
+    }
+
+    #[test]
+    fn g1_assign_ops_and_subtraction() {
+        let a = G1Point::try_from(PrivKey::from_random()).expect("a");
+        let b = G1Point::try_from(PrivKey::from_random()).expect("b");
+
+        let mut c = a;
+        c += b;
+        assert!(c == a + b);
+
+        // Subtraction inverts addition, and a - a is the identity (all-zero bytes)
+        assert!((a + b) - b == a);
+        c -= a;
+        assert!(c == b);
+        assert!(a - a == G1Point::IDENTITY);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn g1_serde_roundtrip_json_and_bincode() {
+        let p = G1Point::try_from(PrivKey::from_random()).expect("p");
+        let c = G1CompressedPoint::try_from(p).expect("compress");
+
+        let json = serde_json::to_string(&c).expect("json");
+        let hex: String = c.0.iter().map(|b| format!("{:02x}", b)).collect();
+        assert_eq!(json, format!("\"{}\"", hex));
+        assert!(serde_json::from_str::<G1CompressedPoint>(&json).expect("from json") == c);
+        let json = serde_json::to_string(&p).expect("json");
+        assert!(serde_json::from_str::<G1Point>(&json).expect("from json") == p);
+
+        let bin = bincode::serialize(&c).expect("bincode");
+        assert!(bincode::deserialize::<G1CompressedPoint>(&bin).expect("from bincode") == c);
+        let bin = bincode::serialize(&p).expect("bincode");
+        assert!(bincode::deserialize::<G1Point>(&bin).expect("from bincode") == p);
+
+        // Wrong length and non-hex characters are rejected
+        assert!(serde_json::from_str::<G1CompressedPoint>(&format!("\"{}\"", &hex[2..])).is_err());
+        let bad = format!("\"zz{}\"", &hex[2..]);
+        let err = serde_json::from_str::<G1CompressedPoint>(&bad).unwrap_err();
+        assert!(err.to_string().contains("malformed hex"));
+        assert!(bincode::deserialize::<G1CompressedPoint>(&bin[..20]).is_err());
+    }
+
+    #[test]
+    fn g1_neg_cancels_to_identity() {
+        let p = G1Point::try_from(PrivKey::from_random()).expect("p");
+        assert!(p + -p == G1Point::IDENTITY);
+        assert!(-(-p) == p);
+        assert!(-G1Point::IDENTITY == G1Point::IDENTITY);
+        assert_eq!((-p).0[..32], p.0[..32]);
+    }
+
+    #[test]
+    fn g1_sum_matches_sequential_add() {
+        use crate::errors::BLSError;
+        let points: Vec<G1Point> =
+            (0..8).map(|_| G1Point::try_from(PrivKey::from_random()).unwrap()).collect();
+        let sequential = points[1..].iter().fold(points[0], |acc, p| acc + *p);
+
+        let by_ref: G1Point = points.iter().sum::<Result<_, _>>().expect("sum refs");
+        let by_value: G1Point = points.into_iter().sum::<Result<_, _>>().expect("sum values");
+        assert!(by_ref == sequential);
+        assert!(by_value == sequential);
+
+        let empty = core::iter::empty::<G1Point>().sum::<Result<G1Point, BLSError>>();
+        assert_eq!(empty.unwrap_err(), BLSError::EmptySignerSet);
+    }
+
+    #[test]
+    fn g1_generator_matches_key_derivation() {
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        assert!(G1Point::try_from(PrivKey(one)).expect("g") == G1Point::generator());
+        assert!(G1Point::generator() + G1Point::neg_generator() == G1Point::IDENTITY);
+
+        let sk = PrivKey::from_random();
+        let expected = G1Point::try_from(PrivKey(sk.0)).expect("pk");
+        assert!(g1_mul(G1Point::generator(), &sk.0).expect("mul") == expected);
+    }
+
+    #[test]
+    fn g1_scalar_mul_edges() {
+        use crate::consts::SCALAR_ORDER;
+        use crate::errors::BLSError;
+
+        let g = G1Point::generator();
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        assert!(g.scalar_mul(&one).expect("1 * G") == g);
+
+        let mut r_minus_one = SCALAR_ORDER;
+        r_minus_one[31] -= 1;
+        assert!(g.scalar_mul(&r_minus_one).expect("(r - 1) * G") == G1Point::neg_generator());
+
+        // r * G is the identity, but r itself is not a canonical scalar
+        assert!(g1_mul(g, &SCALAR_ORDER).expect("raw mul") == G1Point::IDENTITY);
+        assert_eq!(g.scalar_mul(&SCALAR_ORDER).unwrap_err(), BLSError::ScalarOutOfRange);
+        assert_eq!(g.scalar_mul(&[0xff; 32]).unwrap_err(), BLSError::ScalarOutOfRange);
+    }
+
+    #[test]
+    fn g1_scalar_mul_by_two_is_doubling() {
+        let p = G1Point::try_from(PrivKey::from_random()).expect("p");
+        let mut two = [0u8; 32];
+        two[31] = 2;
+        assert!(p.scalar_mul(&two).expect("2p") == p + p);
+    }
+
+    #[test]
+    fn g1_sub_undoes_add() {
+        use num::CheckedSub;
+
+        let a = G1Point::try_from(PrivKey::from_random()).expect("a");
+        let b = G1Point::try_from(PrivKey::from_random()).expect("b");
+        assert!((a + b) - b == a);
+        assert!((a + b).checked_sub(&a).expect("sub") == b);
+        assert!(a.checked_sub(&a).expect("sub") == G1Point::IDENTITY);
+    }
+
+    #[test]
+    fn g1_msm_matches_naive_loop() {
+        use crate::errors::BLSError;
+
+        let points: Vec<G1Point> =
+            (0..4).map(|_| G1Point::try_from(PrivKey::from_random()).unwrap()).collect();
+        let scalars: Vec<[u8; 32]> = (1..=4u8)
+            .map(|w| {
+                let mut k = [0u8; 32];
+                k[31] = w;
+                k
+            })
+            .collect();
+
+        let mut naive = points[0];
+        for (i, p) in points.iter().enumerate() {
+            for _ in 0..=i {
+                naive += *p;
+            }
+        }
+        naive -= points[0];
+        assert!(G1Point::msm(&points, &scalars).expect("msm") == naive);
+
+        assert_eq!(G1Point::msm(&[], &[]).unwrap_err(), BLSError::EmptySignerSet);
+        let err = G1Point::msm(&points, &scalars[..3]).unwrap_err();
+        assert_eq!(err, BLSError::InvalidLength { expected: 4, got: 3 });
+    }
+
+    #[test]
+    fn g1_negate_cancels() {
+        let p = G1Point::try_from(PrivKey::from_random()).expect("p");
+        let neg = p.negate().expect("negate");
+        assert!(crate::bn254::g1_add(p, neg).expect("add") == G1Point::IDENTITY);
+        assert!(neg.negate().expect("negate twice") == p);
+        assert!(G1Point::generator().negate().expect("-G") == G1Point::neg_generator());
+        assert!(G1Point::IDENTITY.negate().expect("-0") == G1Point::IDENTITY);
+
+        // y = p is not a reduced coordinate
+        let mut bad = p;
+        bad.0[32..].copy_from_slice(&crate::consts::MODULUS.to_be_bytes());
+        assert!(bad.negate().is_err());
+    }
+
+    #[test]
+    fn g1_is_on_curve() {
+        let g = G1Point::generator();
+        assert!(g.is_on_curve() && g.is_in_subgroup());
+        assert!(G1Point::neg_generator().is_on_curve());
+        let pk = G1Point::try_from(PrivKey::from_random()).expect("pk");
+        assert!(pk.is_on_curve());
+
+        assert!(!G1Point::IDENTITY.is_on_curve());
+        assert!(G1Point::IDENTITY.is_in_subgroup());
+
+        // Flipping a bit of y leaves (x, y) off the curve
+        let mut flipped = g;
+        flipped.0[63] ^= 1;
+        assert!(!flipped.is_on_curve() && !flipped.is_in_subgroup());
+        assert!(!G1Point([0xff; 64]).is_on_curve());
+    }
+
+    #[cfg(feature = "ark")]
+    #[test]
+    fn ark_conversion_roundtrip() {
+        use ark_ec::AffineRepr;
+
+        let p = G1Point::try_from(PrivKey::from_random()).expect("p");
+        let a = ark_bn254::G1Affine::try_from(&p).expect("to ark");
+        assert!(G1Point::try_from(&a).expect("from ark") == p);
+
+        // Same point as arkworks computes, so the byte order matches
+        let g = ark_bn254::G1Affine::try_from(&G1Point::generator()).expect("g");
+        assert_eq!(g, ark_bn254::G1Affine::generator());
+        let identity = ark_bn254::G1Affine::try_from(&G1Point::IDENTITY).expect("identity");
+        assert!(identity.is_zero());
+        assert!(G1Point::try_from(&identity).expect("identity back") == G1Point::IDENTITY);
+        assert!(ark_bn254::G1Affine::try_from(&G1Point([0xff; 64])).is_err());
+    }
 }
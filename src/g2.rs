@@ -1,89 +1,275 @@
 #[cfg(not(target_os = "solana"))]
 use ark_bn254::Fr;
 #[cfg(not(target_os = "solana"))]
-use ark_ec::AffineRepr;
+use ark_ec::{AffineRepr, CurveGroup};
+#[cfg(not(target_os = "solana"))]
+use ark_ff::PrimeField;
 #[cfg(not(target_os = "solana"))]
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 #[cfg(not(target_os = "solana"))]
+use core::iter::Sum;
+#[cfg(not(target_os = "solana"))]
 use num::CheckedAdd;
 
-use solana_bn254::{
-    compression::prelude::{alt_bn128_g2_compress, alt_bn128_g2_decompress},
-    prelude::alt_bn128_pairing,
-};
+use solana_bn254::compression::prelude::{alt_bn128_g2_compress, alt_bn128_g2_decompress};
 
-use crate::consts::G2_MINUS_ONE;
+use crate::bn254::{g1_add, g1_mul, g2_neg, pairing};
+use crate::consts::{G2_GENERATOR, G2_MINUS_ONE};
+#[cfg(not(target_os = "solana"))]
+use crate::consts::SCALAR_ORDER;
 use crate::errors::BLSError;
-use crate::g1::G1Point;
-use crate::hash::hash_to_curve;
+#[cfg(feature = "serde")]
+use crate::g1::serde_hex;
+use crate::g1::{fmt_hex, write_hex, G1Point};
+use crate::hash::{
+    hash_aad, hash_context, hash_epoch, hash_to_curve, hash_to_curve_counted, hash_to_curve_pop,
+};
+use crate::privkey::{check_security_acknowledged, check_signing_scalar, ct_eq_bytes, PrivKey};
+use crate::utils::rlc_scalars;
 
-#[derive(Clone, Copy)]
+/// Equality and hashing are bit-for-bit over the encoding, not over the curve point. Canonical
+/// encodings from this crate compare as expected, but a non-reduced coordinate or a compressed
+/// vs uncompressed form of the same point won't be equal unless normalized first.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct G2Point(pub [u8; 128]);
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct G2CompressedPoint(pub [u8; 64]);
 
+impl AsRef<[u8]> for G2Point {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for G2Point {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for G2CompressedPoint {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for G2CompressedPoint {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// Prints the four 32 byte field components in wire order: `G2Point(0x.., 0x.., 0x.., 0x..)`.
+impl core::fmt::Debug for G2Point {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("G2Point(")?;
+        for (i, limb) in self.0.chunks(32).enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            write_hex(f, limb)?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl core::fmt::Debug for G2CompressedPoint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt_hex(f, "G2CompressedPoint", &self.0)
+    }
+}
+
+impl core::fmt::Display for G2CompressedPoint {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write_hex(f, &self.0)
+    }
+}
+
 impl G2Point {
+    /// The G2 generator, in the syscall layout (see consts::G2_GENERATOR).
+    pub const fn generator() -> G2Point {
+        G2Point(G2_GENERATOR)
+    }
+
+    /// The negated G2 generator. Pairs with a G1 point to move it to the other side of an equation.
+    #[cfg(not(target_os = "solana"))]
+    pub fn neg_generator() -> G2Point {
+        -G2Point::generator()
+    }
+
+    /// Whether this is the point at infinity (all-zero bytes).
+    pub fn is_identity(&self) -> bool {
+        self.0 == [0u8; 128]
+    }
+
+    /// Verify a signature over `message` under this public key.
+    /// Output:
+    /// - Ok if e(H(m), pk) * e(sig, -G2) == 1, Err(BLSVerificationError) otherwise
+    /// - Err(PointAtInfinity) if the key or the signature is the identity, since the identity
+    ///   key with the identity signature would pass for every message
     pub fn verify<T: AsRef<[u8]>>(&self, signature: &G1Point, message: T) -> Result<(), BLSError> {
-        let mut input = [0u8; 384];
-
-        // Hash message to curve
-        input[..64].clone_from_slice(&hash_to_curve(message)?.0);
-        // Public key (uncompressed)
-        input[64..192].clone_from_slice(&self.0);
-        // Signature (uncompressed)
-        input[192..256].clone_from_slice(&signature.0);
-        // Pair with negative generator in G2
-        input[256..].clone_from_slice(&G2_MINUS_ONE);
-
-        // Calculate result
-        if let Ok(r) = alt_bn128_pairing(&input) {
-            if r.eq(&[
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x01,
-            ]) {
-                Ok(())
-            } else {
-                Err(BLSError::BLSVerificationError)
-            }
+        self.verify_hash(signature, hash_to_curve(message)?)
+    }
+
+    /// verify against an already hashed message, for the variants with their own domain tag.
+    fn verify_hash(&self, signature: &G1Point, h: G1Point) -> Result<(), BLSError> {
+        if self.is_identity() || signature.is_identity() {
+            return Err(BLSError::PointAtInfinity);
+        }
+
+        // e(H(m), pk) * e(sig, -G2) == 1
+        if pairing(&[
+            (h, *self),
+            (*signature, G2Point(G2_MINUS_ONE)),
+        ])? {
+            Ok(())
         } else {
-            Err(BLSError::AltBN128PairingError)
+            Err(BLSError::BLSVerificationError)
+        }
+    }
+
+    /// Verify and report the hash-to-curve nonce, for correlating slow verifies with messages
+    /// that needed many try-and-increment iterations.
+    /// Output:
+    /// - (pairing result, nonce from hash_to_curve_counted); a bad signature is Ok((false, n))
+    /// - Err(PointAtInfinity) if the key or the signature is the identity, as in verify
+    pub fn verify_profiled<T: AsRef<[u8]>>(
+        &self,
+        signature: &G1Point,
+        message: T,
+    ) -> Result<(bool, u8), BLSError> {
+        if self.is_identity() || signature.is_identity() {
+            return Err(BLSError::PointAtInfinity);
+        }
+        let (h, nonce) = hash_to_curve_counted(message)?;
+        let ok = pairing(&[(h, *self), (*signature, G2Point(G2_MINUS_ONE))])?;
+        Ok((ok, nonce))
+    }
+
+    /// Check that this point lies in the prime-order G2 subgroup.
+    /// Notes:
+    /// - The pairing syscall rejects G2 inputs outside the subgroup (EIP-197), so this runs a
+    ///   one-pair pairing e(0, self) and reports whether the input was accepted. It works
+    ///   on-chain, at the cost of a pairing.
+    /// - The identity is in the subgroup.
+    pub fn is_in_subgroup(&self) -> bool {
+        pairing(&[(G1Point([0u8; 64]), *self)]).is_ok()
+    }
+
+    /// Verify a signature made with PrivKey::sign_context under the same context label.
+    pub fn verify_context(
+        &self,
+        signature: &G1Point,
+        context: &[u8],
+        message: &[u8],
+    ) -> Result<(), BLSError> {
+        self.verify_hash(signature, hash_context(context, message)?)
+    }
+
+    /// Verify a signature made with PrivKey::sign_with_aad against independently supplied AAD.
+    pub fn verify_with_aad(
+        &self,
+        signature: &G1Point,
+        message: &[u8],
+        aad: &[u8],
+    ) -> Result<(), BLSError> {
+        self.verify_hash(signature, hash_aad(message, aad)?)
+    }
+
+    /// Verify a signature made with PrivKey::sign_valid_from.
+    /// Output:
+    /// - Err(BLSVerificationError) if current_epoch < claimed_epoch, otherwise the result of
+    ///   verifying the signature for claimed_epoch
+    pub fn verify_valid_from(
+        &self,
+        signature: &G1Point,
+        current_epoch: u64,
+        claimed_epoch: u64,
+        message: &[u8],
+    ) -> Result<(), BLSError> {
+        if current_epoch < claimed_epoch {
+            return Err(BLSError::BLSVerificationError);
         }
+        self.verify_hash(signature, hash_epoch(claimed_epoch, message)?)
+    }
+}
+
+/// Header of the 96 byte framed form of a G2CompressedPoint (see G2CompressedPoint::to_framed).
+///
+/// Same layout as G1_FRAME_TAG, zero padded to 32 bytes.
+pub const G2_FRAME_TAG: [u8; 32] = {
+    let tag = b"\x80BN254-G2-FRAME";
+    let mut out = [0u8; 32];
+    let mut i = 0;
+    while i < tag.len() {
+        out[i] = tag[i];
+        i += 1;
+    }
+    out
+};
+
+/// Same comparison as the inherent ct_eq, returned as a subtle::Choice.
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeEq for G2CompressedPoint {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0[..].ct_eq(&other.0[..])
     }
 }
 
 impl G2CompressedPoint {
+    /// Compare two compressed points in constant time, e.g. when deduplicating keys where the
+    /// position of the first differing byte shouldn't be observable. Same notes as PrivKey::ct_eq.
+    pub fn ct_eq(&self, other: &G2CompressedPoint) -> bool {
+        ct_eq_bytes(&self.0, &other.0)
+    }
+
+    /// Decompress and check that the point is in the prime-order subgroup.
+    /// Output:
+    /// - The uncompressed point, or Err(G2PointDecompressionError) if it is off the curve or
+    ///   outside the subgroup
+    ///
+    /// Notes:
+    /// - Plain G2Point::try_from only recovers a curve point. Use this for any key that didn't
+    ///   come from a trusted registry: keys read from instruction data before they're stored,
+    ///   and keys fed to verify_possession or the aggregate verifiers.
+    /// - G2 scalar multiplication has no syscall, so the check is the pairing-based
+    ///   is_in_subgroup rather than a multiply-by-r test. It costs one pairing.
+    pub fn decompress_checked(self) -> Result<G2Point, BLSError> {
+        let p = G2Point::try_from(self)?;
+        if !p.is_in_subgroup() {
+            return Err(BLSError::G2PointDecompressionError);
+        }
+        Ok(p)
+    }
+
     pub fn verify<T: AsRef<[u8]>>(
         &self,
         signature: &G1Point,
         message: T,
     ) -> Result<(), BLSError> {
-        let mut input = [0u8; 384];
-
-        // Hash message to curve
-        input[..64].clone_from_slice(&hash_to_curve(message)?.0);
-        // Decompress public key
-        input[64..192].clone_from_slice(&G2Point::try_from(*self)?.0);
-        // Signature (uncompressed)
-        input[192..256].clone_from_slice(&signature.0);
-        // Pair with negative generator in G2
-        input[256..].clone_from_slice(&G2_MINUS_ONE);
-
-        // Calculate result
-        if let Ok(r) = alt_bn128_pairing(&input) {
-            if r.eq(&[
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-                0x00, 0x00, 0x00, 0x01,
-            ]) {
-                Ok(())
-            } else {
-                Err(BLSError::BLSVerificationError)
-            }
-        } else {
-            Err(BLSError::AltBN128PairingError)
+        // Decompress public key and verify
+        G2Point::try_from(*self)?.verify(signature, message)
+    }
+
+    /// Frame the 64 byte compressed point as a 96 byte blob for APIs sized for BLS12-381.
+    /// Output:
+    /// - G2_FRAME_TAG (32) || compressed point (64)
+    pub fn to_framed(&self) -> [u8; 96] {
+        let mut out = [0u8; 96];
+        out[..32].copy_from_slice(&G2_FRAME_TAG);
+        out[32..].copy_from_slice(&self.0);
+        out
+    }
+
+    /// Strip the framing added by to_framed.
+    /// Output:
+    /// - The compressed point, or Err(SerializationError) if the header is not G2_FRAME_TAG
+    pub fn from_framed(bytes: &[u8; 96]) -> Result<G2CompressedPoint, BLSError> {
+        if bytes[..32] != G2_FRAME_TAG {
+            return Err(BLSError::SerializationError);
         }
+        Ok(G2CompressedPoint(bytes[32..].try_into().unwrap()))
     }
 }
 
@@ -96,6 +282,40 @@ impl core::ops::Add for G2Point {
     }
 }
 
+#[cfg(not(target_os = "solana"))]
+impl core::ops::AddAssign for G2Point {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+/// a - b = a + (-b).
+#[cfg(not(target_os = "solana"))]
+impl core::ops::Sub for G2Point {
+    type Output = G2Point;
+
+    fn sub(self, rhs: Self) -> G2Point {
+        self.checked_add(&-rhs).expect("G2Point subtraction failed")
+    }
+}
+
+/// Negate both Fq2 coefficients of y on the raw bytes. This gives the same point as
+/// ark_bn254::G2Affine::neg, but also works on-chain, where there is no G2 arithmetic.
+impl core::ops::Neg for G2Point {
+    type Output = G2Point;
+
+    fn neg(self) -> G2Point {
+        g2_neg(&self)
+    }
+}
+
+#[cfg(not(target_os = "solana"))]
+impl core::ops::SubAssign for G2Point {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
 #[cfg(not(target_os = "solana"))]
 impl CheckedAdd for G2Point {
     fn checked_add(&self, rhs: &Self) -> Option<Self> {
@@ -126,6 +346,126 @@ impl CheckedAdd for G2Point {
     }
 }
 
+#[cfg(not(target_os = "solana"))]
+impl G2Point {
+    /// Multiply this point by a scalar. Off-chain only; there is no G2 multiplication syscall.
+    /// Input:
+    /// - scalar: 32 byte big-endian scalar in 0..r
+    ///
+    /// Output:
+    /// - scalar * self, or Err(ScalarOutOfRange) if the scalar is not below r
+    ///
+    /// Notes:
+    /// - G2Point::generator().scalar_mul(sk) is the public key for sk, e.g. for DKG commitments.
+    pub fn scalar_mul(&self, scalar: &[u8; 32]) -> Result<G2Point, BLSError> {
+        if *scalar >= SCALAR_ORDER {
+            return Err(BLSError::ScalarOutOfRange);
+        }
+        let k = Fr::from_be_bytes_mod_order(scalar);
+        G2Point::from_ark(&(self.to_ark()? * k).into_affine())
+    }
+
+    /// Negate this point. Off-chain only.
+    /// Output:
+    /// - -self, or Err(SerializationError) if the bytes aren't a valid G2 point
+    ///
+    /// Notes:
+    /// - Unlike the Neg operator, which flips the y bytes without looking at them, this
+    ///   round-trips through arkworks so malformed input is caught.
+    pub fn negate(&self) -> Result<G2Point, BLSError> {
+        G2Point::from_ark(&-self.to_ark()?)
+    }
+
+    /// Check that the bytes are an affine point on the twist y^2 = x^3 + 3 / (9 + u). Off-chain
+    /// only.
+    /// Output:
+    /// - true if all four coordinates are reduced mod p and satisfy the curve equation
+    ///
+    /// Notes:
+    /// - The all-zero identity encoding returns false, matching G1Point::is_on_curve.
+    /// - Being on the curve doesn't imply being in the subgroup; use is_in_subgroup for that.
+    pub fn is_on_curve(&self) -> bool {
+        if self.is_identity() {
+            return false;
+        }
+        let mut bytes = self.0;
+        bytes[..64].reverse();
+        bytes[64..].reverse();
+        ark_bn254::G2Affine::deserialize_uncompressed_unchecked(&bytes[..])
+            .is_ok_and(|p| p.is_on_curve())
+    }
+
+    /// Convert to an arkworks affine point (all-zero bytes are the identity).
+    pub(crate) fn to_ark(self) -> Result<ark_bn254::G2Affine, BLSError> {
+        if self.0 == [0u8; 128] {
+            return Ok(ark_bn254::G2Affine::identity());
+        }
+        // [x_c1 | x_c0] big-endian reversed is [x_c0 | x_c1] little-endian, and likewise for y
+        let mut bytes = self.0;
+        bytes[..64].reverse();
+        bytes[64..].reverse();
+        ark_bn254::G2Affine::deserialize_uncompressed(&bytes[..])
+            .map_err(|_| BLSError::SerializationError)
+    }
+
+    /// Convert from an arkworks affine point (the identity becomes all-zero bytes).
+    pub(crate) fn from_ark(p: &ark_bn254::G2Affine) -> Result<G2Point, BLSError> {
+        let mut bytes = [0u8; 128];
+        if let Some((x, y)) = p.xy() {
+            x.serialize_uncompressed(&mut bytes[..64])
+                .map_err(|_| BLSError::SerializationError)?;
+            y.serialize_uncompressed(&mut bytes[64..])
+                .map_err(|_| BLSError::SerializationError)?;
+            bytes[..64].reverse();
+            bytes[64..].reverse();
+        }
+        Ok(G2Point(bytes))
+    }
+}
+
+/// Convert to an arkworks affine point, for off-chain computation (ark feature).
+/// Err(SerializationError) if the bytes aren't a valid G2 point; all-zero bytes are the identity.
+#[cfg(all(feature = "ark", not(target_os = "solana")))]
+impl TryFrom<&G2Point> for ark_bn254::G2Affine {
+    type Error = BLSError;
+
+    fn try_from(value: &G2Point) -> Result<Self, Self::Error> {
+        value.to_ark()
+    }
+}
+
+/// Convert from an arkworks affine point (ark feature); the identity becomes all-zero bytes.
+#[cfg(all(feature = "ark", not(target_os = "solana")))]
+impl TryFrom<&ark_bn254::G2Affine> for G2Point {
+    type Error = BLSError;
+
+    fn try_from(value: &ark_bn254::G2Affine) -> Result<Self, Self::Error> {
+        G2Point::from_ark(value)
+    }
+}
+
+/// Sum pubkeys with `iter.sum::<Result<G2Point, BLSError>>()`.
+/// An empty iterator is Err(EmptySignerSet). Off-chain only, like the other G2 addition.
+#[cfg(not(target_os = "solana"))]
+impl Sum<G2Point> for Result<G2Point, BLSError> {
+    fn sum<I: Iterator<Item = G2Point>>(mut iter: I) -> Self {
+        let mut acc = iter.next().ok_or(BLSError::EmptySignerSet)?;
+        for p in iter {
+            acc = acc
+                .checked_add(&p)
+                .ok_or(BLSError::G2PointCompressionError)?;
+        }
+        Ok(acc)
+    }
+}
+
+#[cfg(not(target_os = "solana"))]
+impl<'a> Sum<&'a G2Point> for Result<G2Point, BLSError> {
+    fn sum<I: Iterator<Item = &'a G2Point>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 #[cfg(not(target_os = "solana"))]
 impl TryFrom<&crate::privkey::PrivKey> for G2CompressedPoint {
     type Error = BLSError;
@@ -174,6 +514,7 @@ impl TryFrom<&G2Point> for G2CompressedPoint {
     }
 }
 
+/// Decompress without a subgroup check; see G2CompressedPoint::decompress_checked.
 impl TryFrom<G2CompressedPoint> for G2Point {
     type Error = BLSError;
 
@@ -184,11 +525,298 @@ impl TryFrom<G2CompressedPoint> for G2Point {
     }
 }
 
+impl TryFrom<&[u8]> for G2Point {
+    type Error = BLSError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = value
+            .try_into()
+            .map_err(|_| BLSError::InvalidLength { expected: 128, got: value.len() })?;
+        Ok(G2Point(bytes))
+    }
+}
+
+impl TryFrom<&[u8]> for G2CompressedPoint {
+    type Error = BLSError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = value
+            .try_into()
+            .map_err(|_| BLSError::InvalidLength { expected: 64, got: value.len() })?;
+        Ok(G2CompressedPoint(bytes))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for G2Point {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_hex::serialize(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for G2Point {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde_hex::deserialize(deserializer).map(G2Point)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for G2CompressedPoint {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serde_hex::serialize(&self.0, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for G2CompressedPoint {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        serde_hex::deserialize(deserializer).map(G2CompressedPoint)
+    }
+}
+
+/// Prove possession of the secret key for `pk`: sk * H_pop(pk).
+/// Input:
+/// - sk: the secret key
+/// - pk: sk's G2 public key, as it will be registered
+///
+/// Output:
+/// - The PoP in G1, to submit alongside pk
+/// - Err(ScalarOutOfRange) if sk is zero or not below r, like PrivKey::sign
+///
+/// Notes:
+/// - Works on-chain, since the caller supplies pk. A pk that doesn't belong to sk just gives a
+///   proof that fails verify_possession.
+/// - Same output as PrivKey::prove_possession.
+pub fn prove_possession(sk: &PrivKey, pk: &G2Point) -> Result<G1Point, BLSError> {
+    check_security_acknowledged()?;
+    check_signing_scalar(&sk.0)?;
+    g1_mul(hash_to_curve_pop(pk)?, &sk.0).map_err(|_| BLSError::BLSSigningError)
+}
+
+/// Verify a proof of possession made with PrivKey::prove_possession.
+/// Output:
+/// - Ok if e(H_pop(pk), pk) * e(pop, -G2) == 1, Err otherwise
+///
+/// Notes:
+/// - H_pop hashes the uncompressed key bytes under hash::POP_DST, a different domain from
+///   message signing, so a PoP never verifies as a signature over any message (or vice versa).
+/// - Check this once when a key is registered; verify_fast_aggregate relies on it.
+/// - An identity key or proof is rejected with PointAtInfinity; otherwise the identity key
+///   would register with the identity proof.
+pub fn verify_possession(pk: &G2Point, pop: &G1Point) -> Result<(), BLSError> {
+    if pk.is_identity() || pop.is_identity() {
+        return Err(BLSError::PointAtInfinity);
+    }
+    if pairing(&[
+        (hash_to_curve_pop(pk)?, *pk),
+        (*pop, G2Point(G2_MINUS_ONE)),
+    ])? {
+        Ok(())
+    } else {
+        Err(BLSError::BLSVerificationError)
+    }
+}
+
+/// Verify many proofs of possession with one pairing.
+/// Input:
+/// - pops: (PK_i, pop_i) for each key
+///
+/// Output:
+/// - Ok if every proof verifies (with overwhelming probability), Err otherwise
+///
+/// Notes:
+/// - Checks prod e(r_i * H_pop(PK_i), PK_i) * e(sum r_i * pop_i, -G2) == 1, with the r_i
+///   derived from every input like verify_batch, so invalid proofs can't cancel each other out.
+/// - An empty list is rejected, and an identity key or proof anywhere in it is
+///   Err(PointAtInfinity).
+pub fn verify_possession_batch(pops: &[(G2Point, G1Point)]) -> Result<(), BLSError> {
+    if pops.is_empty() {
+        return Err(BLSError::EmptyInput);
+    }
+    if pops.iter().any(|(pk, pop)| pk.is_identity() || pop.is_identity()) {
+        return Err(BLSError::PointAtInfinity);
+    }
+
+    let mut transcript = Vec::with_capacity(pops.len() * 192);
+    for (pk, pop) in pops {
+        transcript.extend_from_slice(&pk.0);
+        transcript.extend_from_slice(&pop.0);
+    }
+    let scalars = rlc_scalars(b"BLS-BN254-POP-BATCH", &transcript, pops.len());
+
+    let mut pairs = Vec::with_capacity(pops.len() + 1);
+    let mut pop_sum: Option<G1Point> = None;
+    for ((pk, pop), r) in pops.iter().zip(&scalars) {
+        pairs.push((g1_mul(hash_to_curve_pop(pk)?, r)?, *pk));
+        let weighted = g1_mul(*pop, r)?;
+        pop_sum = Some(match pop_sum {
+            None => weighted,
+            Some(acc) => g1_add(acc, weighted)?,
+        });
+    }
+    pairs.push((pop_sum.unwrap(), G2Point(G2_MINUS_ONE)));
+
+    if pairing(&pairs)? {
+        Ok(())
+    } else {
+        Err(BLSError::BLSVerificationError)
+    }
+}
+
+/// A G2 public key in either encoding, for registries that mix the two.
+#[derive(Clone, Copy)]
+pub enum PubKeyInput {
+    Uncompressed(G2Point),
+    Compressed(G2CompressedPoint),
+}
+
+impl TryFrom<&PubKeyInput> for G2Point {
+    type Error = BLSError;
+
+    fn try_from(value: &PubKeyInput) -> Result<Self, Self::Error> {
+        match value {
+            PubKeyInput::Uncompressed(p) => Ok(*p),
+            PubKeyInput::Compressed(c) => G2Point::try_from(*c),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{G2CompressedPoint, G2Point};
     use crate::g1::{G1CompressedPoint, G1Point};
     use crate::privkey::PrivKey;
+    use crate::test_utils::off_subgroup_compressed;
+
+    #[test]
+    fn g2_framed_roundtrip_and_rejects_bad_header() {
+        let pk = G2CompressedPoint::try_from(&PrivKey::from_random()).expect("g2c from sk");
+        let framed = pk.to_framed();
+        let unframed = G2CompressedPoint::from_framed(&framed).expect("unframe");
+        assert_eq!(unframed.0, pk.0);
+        G2Point::try_from(unframed).expect("still decompresses");
+
+        // A G1 header or a flipped padding byte is not a valid G2 frame
+        let mut bad = framed;
+        bad[8] = b'1';
+        assert!(G2CompressedPoint::from_framed(&bad).is_err());
+        let mut bad = framed;
+        bad[31] = 1;
+        assert!(G2CompressedPoint::from_framed(&bad).is_err());
+    }
+
+    #[test]
+    fn verify_profiled_reports_hash_nonce() {
+        let sk = PrivKey::from_random();
+        let pk = G2Point::try_from(&sk).expect("g2 from sk");
+
+        // Pick a message that needs at least one retry so the nonce is interesting
+        let msg = (0u32..)
+            .map(|i| i.to_be_bytes())
+            .find(|m| crate::hash::hash_to_curve_counted(m).unwrap().1 > 0)
+            .unwrap();
+        let (_, expected) = crate::hash::hash_to_curve_counted(msg).expect("hash");
+
+        let sig = sk.sign(msg).expect("sign");
+        assert_eq!(pk.verify_profiled(&sig, msg).expect("profiled"), (true, expected));
+
+        let wrong = sk.sign(b"other").expect("sign");
+        assert_eq!(pk.verify_profiled(&wrong, msg).expect("profiled"), (false, expected));
+    }
+
+    #[test]
+    fn g2_equality_compares_bytes() {
+        let sk = PrivKey::from_random();
+        let a = G2Point::try_from(&sk).expect("a");
+        let b = sk.pubkey_g2().expect("b");
+        assert!(a == b);
+        assert!(a != G2Point::try_from(&PrivKey::from_random()).expect("other"));
+
+        let c = G2CompressedPoint::try_from(&a).expect("compress");
+        assert!(c == G2CompressedPoint::try_from(&sk).expect("compressed from sk"));
+        assert!(G2Point::try_from(c).expect("decompress") == a);
+    }
+
+    #[test]
+    fn g2_as_ref_feeds_generic_code() {
+        let sk = PrivKey::from_random();
+        let pk = G2Point::try_from(&sk).expect("g2 from sk");
+        let pk_c = G2CompressedPoint::try_from(&sk).expect("g2c from sk");
+        assert_eq!(pk.as_ref().len(), 128);
+        assert_eq!(pk_c.as_ref().len(), 64);
+
+        // Anything taking AsRef<[u8]> accepts the point directly
+        let h = crate::hash::hash_to_curve(pk_c).expect("hash");
+        assert_eq!(h, crate::hash::hash_to_curve(pk_c.0).expect("hash bytes"));
+    }
+
+    #[test]
+    fn g2_try_from_slice_checks_length() {
+        let sk = PrivKey::from_random();
+        let pk = G2Point::try_from(&sk).expect("pk");
+        let pk_c = G2CompressedPoint::try_from(&sk).expect("pk_c");
+        assert!(G2Point::try_from(&pk.0[..]).expect("exact") == pk);
+        assert!(G2CompressedPoint::try_from(&pk_c.0[..]).expect("exact") == pk_c);
+
+        let long = [0u8; 129];
+        for len in [0, 127, 129] {
+            assert!(G2Point::try_from(&long[..len]).is_err());
+        }
+        for len in [0, 63, 65] {
+            assert!(G2CompressedPoint::try_from(&long[..len]).is_err());
+        }
+    }
+
+    #[test]
+    fn g2_assign_ops_and_subtraction() {
+        let a = G2Point::try_from(&PrivKey::from_random()).expect("a");
+        let b = G2Point::try_from(&PrivKey::from_random()).expect("b");
+
+        let mut c = a;
+        c += b;
+        assert!(c == a + b);
+        assert!((a + b) - b == a);
+        c -= a;
+        assert!(c == b);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn g2_serde_roundtrip_json_and_bincode() {
+        let sk = PrivKey::from_random();
+        let pk = G2Point::try_from(&sk).expect("pk");
+        let pk_c = G2CompressedPoint::try_from(&sk).expect("pk_c");
+
+        let json = serde_json::to_string(&pk_c).expect("json");
+        assert_eq!(json.len(), 2 + 128);
+        assert!(serde_json::from_str::<G2CompressedPoint>(&json).expect("from json") == pk_c);
+        let json = serde_json::to_string(&pk).expect("json");
+        assert!(serde_json::from_str::<G2Point>(&json).expect("from json") == pk);
+
+        let bin = bincode::serialize(&pk_c).expect("bincode");
+        assert!(bincode::deserialize::<G2CompressedPoint>(&bin).expect("from bincode") == pk_c);
+        let bin = bincode::serialize(&pk).expect("bincode");
+        assert!(bincode::deserialize::<G2Point>(&bin).expect("from bincode") == pk);
+
+        // A G1-sized hex string is not a G2 key
+        assert!(serde_json::from_str::<G2CompressedPoint>(&format!("\"{}\"", "00".repeat(32)))
+            .is_err());
+    }
+
+    #[test]
+    fn g2_neg_matches_arkworks() {
+        use ark_serialize::CanonicalDeserialize;
+        let p = G2Point::try_from(&PrivKey::from_random()).expect("p");
+        let to_ark = |q: G2Point| {
+            let mut c = G2CompressedPoint::try_from(&q).expect("compress").0;
+            c.reverse();
+            ark_bn254::G2Affine::deserialize_compressed(&c[..]).expect("ark")
+        };
+        assert_eq!(to_ark(-p), -to_ark(p));
+        assert!(-(-p) == p);
+        assert_eq!((p + -p).0, [0u8; 128]);
+    }
 
     #[test]
     fn g2_keygen_roundtrip_random() {
@@ -236,8 +864,254 @@ mod tests {
         let sk = PrivKey::from_random();
         let msg = b"sig-rt";
         let sig = sk.sign(msg).expect("sign");
-        let sig_c = G1CompressedPoint::try_from(sig.clone()).expect("compress sig");
+        let sig_c = G1CompressedPoint::try_from(sig).expect("compress sig");
         let sig_rt = G1Point::try_from(&sig_c).expect("decompress sig");
         assert_eq!(sig.0, sig_rt.0, "G1 sig compress/decompress mismatch");
     }
+
+    #[test]
+    fn subgroup_check_rejects_off_subgroup_point() {
+        let pk = G2Point::try_from(&PrivKey::from_random()).expect("pk");
+        assert!(pk.is_in_subgroup());
+
+        // Decompression alone accepts the point; the subgroup check must not
+        let bad = G2Point::try_from(off_subgroup_compressed()).expect("decompress");
+        assert!(!bad.is_in_subgroup());
+    }
+
+    #[test]
+    fn decompress_checked_rejects_off_subgroup_point() {
+        let pkc = G2CompressedPoint::try_from(&PrivKey::from_random()).expect("pkc");
+        assert_eq!(pkc.decompress_checked().expect("checked"), G2Point::try_from(pkc).unwrap());
+        assert_eq!(
+            off_subgroup_compressed().decompress_checked(),
+            Err(crate::errors::BLSError::G2PointDecompressionError)
+        );
+    }
+
+    #[test]
+    fn g2_sum_matches_sequential_add() {
+        let points: Vec<G2Point> =
+            (0..8).map(|_| G2Point::try_from(&PrivKey::from_random()).unwrap()).collect();
+        let sequential = points[1..].iter().fold(points[0], |acc, p| acc + *p);
+
+        let sum: G2Point = points.iter().sum::<Result<_, _>>().expect("sum");
+        assert_eq!(sum, sequential);
+        assert_eq!(
+            core::iter::empty::<G2Point>().sum::<Result<G2Point, _>>(),
+            Err(crate::errors::BLSError::EmptySignerSet)
+        );
+    }
+
+    #[test]
+    fn g2_points_dedupe_in_hash_set() {
+        use std::collections::HashSet;
+
+        let sk = PrivKey::from_random();
+        let pk = G2Point::try_from(&sk).expect("pk");
+        let other = G2Point::try_from(&PrivKey::from_random()).expect("other");
+        let set: HashSet<G2Point> = [pk, other, pk].into_iter().collect();
+        assert_eq!(set.len(), 2);
+
+        let compressed: HashSet<G2CompressedPoint> =
+            [G2CompressedPoint::try_from(&sk).expect("pkc"); 3].into_iter().collect();
+        assert_eq!(compressed.len(), 1);
+    }
+
+    #[test]
+    fn g2_debug_groups_components_and_display_is_hex() {
+        let sk = PrivKey::from_random();
+        let pk = G2Point::try_from(&sk).expect("pk");
+        let debug = format!("{:?}", pk);
+        let limbs: Vec<&str> = debug["G2Point(".len()..debug.len() - 1].split(", ").collect();
+        assert_eq!(limbs.len(), 4);
+        for (limb, bytes) in limbs.iter().zip(pk.0.chunks(32)) {
+            let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+            assert_eq!(*limb, format!("0x{}", hex));
+        }
+
+        let pkc = G2CompressedPoint::try_from(&sk).expect("pkc");
+        let hex: String = pkc.0.iter().map(|b| format!("{:02x}", b)).collect();
+        assert_eq!(pkc.to_string(), format!("0x{}", hex));
+        assert_eq!(format!("{:?}", pkc), format!("G2CompressedPoint(0x{})", hex));
+    }
+
+    #[test]
+    fn g2_generator_matches_key_derivation() {
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        assert_eq!(G2Point::try_from(&PrivKey(one)).expect("g2"), G2Point::generator());
+
+        // e(G1, G2) * e(G1, -G2) == 1
+        let g1 = G1Point::generator();
+        assert!(crate::bn254::pairing(&[
+            (g1, G2Point::generator()),
+            (g1, G2Point::neg_generator()),
+        ])
+        .expect("pairing"));
+    }
+
+    #[test]
+    fn g2_scalar_mul_matches_key_derivation() {
+        let g = G2Point::generator();
+        let mut keys = vec![[0u8; 32], crate::consts::SCALAR_ORDER];
+        keys[0][31] = 7;
+        keys[1][31] -= 1;
+        keys.extend((0..3).map(|_| PrivKey::from_random().0));
+        for sk in keys {
+            assert_eq!(g.scalar_mul(&sk).expect("mul"), G2Point::try_from(&PrivKey(sk)).unwrap());
+        }
+
+        assert_eq!(g.scalar_mul(&[0u8; 32]).expect("zero"), G2Point([0u8; 128]));
+        assert_eq!(
+            g.scalar_mul(&crate::consts::SCALAR_ORDER),
+            Err(crate::errors::BLSError::ScalarOutOfRange)
+        );
+    }
+
+    #[test]
+    fn possession_rejects_identity() {
+        use super::{verify_possession, verify_possession_batch};
+        use crate::errors::BLSError;
+
+        // The identity key with the identity proof satisfies the pairing equation
+        let identity_pk = G2Point([0u8; 128]);
+        let err = verify_possession(&identity_pk, &G1Point::IDENTITY).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+
+        let sk = PrivKey::from_random();
+        let pk = G2Point::try_from(&sk).unwrap();
+        let pop = sk.prove_possession().unwrap();
+        let err = verify_possession(&pk, &G1Point::IDENTITY).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        let err = verify_possession_batch(&[(pk, pop), (identity_pk, G1Point::IDENTITY)]);
+        assert_eq!(err.unwrap_err(), BLSError::PointAtInfinity);
+        verify_possession_batch(&[(pk, pop)]).expect("batch");
+    }
+
+    #[test]
+    fn prove_possession_rejects_invalid_scalar() {
+        use super::prove_possession;
+        use crate::consts::SCALAR_ORDER;
+        use crate::errors::BLSError;
+
+        // A zero key would prove possession of the identity key
+        let pk = G2Point([0u8; 128]);
+        let err = prove_possession(&PrivKey([0u8; 32]), &pk).unwrap_err();
+        assert_eq!(err, BLSError::ScalarOutOfRange);
+        let err = prove_possession(&PrivKey(SCALAR_ORDER), &G2Point::generator()).unwrap_err();
+        assert_eq!(err, BLSError::ScalarOutOfRange);
+    }
+
+    #[test]
+    fn possession_free_fns_and_batch() {
+        use super::{prove_possession, verify_possession, verify_possession_batch};
+
+        let keys: Vec<PrivKey> = (0..4).map(|_| PrivKey::from_random()).collect();
+        let mut pops: Vec<(G2Point, G1Point)> = keys
+            .iter()
+            .map(|k| {
+                let pk = G2Point::try_from(k).unwrap();
+                (pk, prove_possession(k, &pk).unwrap())
+            })
+            .collect();
+        assert!(pops[0].1 == keys[0].prove_possession().unwrap());
+        verify_possession_batch(&pops).expect("batch");
+
+        // A proof made with the wrong key fails alone and poisons the batch
+        let wrong = prove_possession(&keys[1], &pops[0].0).unwrap();
+        assert!(verify_possession(&pops[0].0, &wrong).is_err());
+        pops[0].1 = wrong;
+        assert!(verify_possession_batch(&pops).is_err());
+
+        // Swapping two valid proofs keeps the sum but not the per-key equation
+        pops[0].1 = pops[1].1;
+        pops[1].1 = prove_possession(&keys[0], &pops[0].0).unwrap();
+        assert!(verify_possession_batch(&pops).is_err());
+        assert!(verify_possession_batch(&[]).is_err());
+    }
+
+    #[test]
+    fn g2_scalar_mul_identities() {
+        let p = G2Point::try_from(&PrivKey::from_random()).expect("p");
+        let mut k = [0u8; 32];
+        k[31] = 1;
+        assert_eq!(p.scalar_mul(&k).expect("1p"), p);
+        k[31] = 2;
+        assert_eq!(p.scalar_mul(&k).expect("2p"), p + p);
+    }
+
+    #[test]
+    fn verify_rejects_identity_inputs() {
+        use crate::errors::BLSError;
+
+        let sk = PrivKey::from_random();
+        let pk = G2Point::try_from(&sk).unwrap();
+        let sig = sk.sign(b"identity").unwrap();
+        let identity_pk = G2Point([0u8; 128]);
+        assert!(identity_pk.is_identity() && G1Point::IDENTITY.is_identity());
+        assert!(!pk.is_identity() && !sig.is_identity());
+
+        // e(H(m), 0) * e(0, -G2) is one for any m, so both identities must be refused up front
+        let err = identity_pk.verify(&G1Point::IDENTITY, b"anything").unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        assert_eq!(identity_pk.verify(&sig, b"identity").unwrap_err(), BLSError::PointAtInfinity);
+        let err = pk.verify(&G1Point::IDENTITY, b"identity").unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        pk.verify(&sig, b"identity").expect("verify");
+
+        let err = identity_pk.verify_profiled(&G1Point::IDENTITY, b"anything").unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        let err = pk.verify_profiled(&G1Point::IDENTITY, b"identity").unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        assert!(pk.verify_profiled(&sig, b"identity").unwrap().0);
+    }
+
+    #[test]
+    fn g2_negate_cancels() {
+        use ark_ec::CurveGroup;
+
+        let p = G2Point::try_from(&PrivKey::from_random()).expect("p");
+        let neg = p.negate().expect("negate");
+        let sum = p.to_ark().unwrap() + neg.to_ark().unwrap();
+        let sum = G2Point::from_ark(&sum.into_affine());
+        assert_eq!(sum.expect("sum"), G2Point([0u8; 128]));
+        assert_eq!(neg.negate().expect("negate twice"), p);
+        assert_eq!(neg, -p);
+        assert!(G2Point([0xff; 128]).negate().is_err());
+    }
+
+    #[test]
+    fn g2_is_on_curve() {
+        let g = G2Point::generator();
+        assert!(g.is_on_curve() && g.is_in_subgroup());
+        let pk = G2Point::try_from(&PrivKey::from_random()).expect("pk");
+        assert!(pk.is_on_curve());
+
+        assert!(!G2Point([0u8; 128]).is_on_curve());
+
+        let mut flipped = g;
+        flipped.0[127] ^= 1;
+        assert!(!flipped.is_on_curve() && !flipped.is_in_subgroup());
+
+        // On the curve but outside the subgroup
+        let off = G2Point::try_from(off_subgroup_compressed()).expect("decompress");
+        assert!(off.is_on_curve() && !off.is_in_subgroup());
+    }
+
+    #[cfg(feature = "ark")]
+    #[test]
+    fn ark_conversion_roundtrip() {
+        use ark_ec::AffineRepr;
+
+        let p = G2Point::try_from(&PrivKey::from_random()).expect("p");
+        let a = ark_bn254::G2Affine::try_from(&p).expect("to ark");
+        assert_eq!(G2Point::try_from(&a).expect("from ark"), p);
+
+        let g = ark_bn254::G2Affine::try_from(&G2Point::generator()).expect("g");
+        assert_eq!(g, ark_bn254::G2Affine::generator());
+        let identity = ark_bn254::G2Affine::try_from(&G2Point([0u8; 128])).expect("identity");
+        assert!(identity.is_zero());
+        assert!(ark_bn254::G2Affine::try_from(&G2Point([0xff; 128])).is_err());
+    }
 }
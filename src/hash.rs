@@ -1,20 +1,113 @@
 use dashu::integer::UBig;
+use dashu::base::BitTest;
+use dashu::integer::fast_div::ConstDivisor;
+use dashu::integer::modular::Reduced;
 use solana_bn254::compression::prelude::alt_bn128_g1_decompress;
 
-use crate::consts::{MODULUS, NORMALIZE_MODULUS};
+use crate::bn254::g1_add;
+use crate::consts::{MODULUS, NORMALIZE_MODULUS, SCALAR_ORDER};
 use crate::errors::BLSError;
 use crate::g1::G1Point;
+use crate::g2::G2Point;
+use crate::scalar::Fr;
 
-// TODO: Consider replacing the try-and-increment decompression routine with a standard IETF
-// hash-to-curve mapping (ExpandMsgXMD with SHA-256, Simplified SWU, RO) for BN254 G1.
+// hash_to_curve keeps the original try-and-increment map so existing signatures stay valid.
+// hash_to_curve_rfc9380 below is the standard RFC 9380 alternative for new deployments.
 
+/// Domain tag for message signing, used by hash_to_curve and everything built on it.
+pub const DEFAULT_DST: &[u8] = b"BLS-BN254-RO";
+
+/// H(message) in G1 under DEFAULT_DST, with the default SHA-256 expansion.
+/// Shorthand for Sha256::try_hash_to_curve(message, DEFAULT_DST).
 pub fn hash_to_curve<T: AsRef<[u8]>>(message: T) -> Result<G1Point, BLSError> {
+    hash_to_curve_with_dst(message, DEFAULT_DST)
+}
+
+/// A message-to-G1 hash, selected by marker type so signing and verification can be generic
+/// over it (see utils::bls_partial_sign_with_hasher and verify_fast_aggregate_with_hasher).
+pub trait HashToCurve {
+    /// Hash `message` under the domain tag `dst` to a G1 point.
+    fn try_hash_to_curve(message: &[u8], dst: &[u8]) -> Result<G1Point, BLSError>;
+}
+
+/// The default hasher: try-and-increment over SHA-256, i.e. hash_to_curve_with_dst.
+pub struct Sha256;
+
+impl HashToCurve for Sha256 {
+    fn try_hash_to_curve(message: &[u8], dst: &[u8]) -> Result<G1Point, BLSError> {
+        hash_to_curve_with_dst(message, dst)
+    }
+}
+
+/// hash_to_curve under a caller-chosen domain tag.
+/// Output:
+/// - H_dst(message) in G1; hash_to_curve(m) == hash_to_curve_with_dst(m, DEFAULT_DST)
+///
+/// - Err(InvalidLength) if dst is longer than 255 bytes
+///
+/// Notes:
+/// - Use a distinct tag per protocol (PoP, blind signatures, app-specific aggregation) so a
+///   signature from one can never be replayed in another.
+/// - Tags other than DEFAULT_DST and POP_DST are hashed with a length prefix, so no
+///   (tag, message) pair can produce the same hash input as another.
+pub fn hash_to_curve_with_dst<T: AsRef<[u8]>>(
+    message: T,
+    dst: &[u8],
+) -> Result<G1Point, BLSError> {
+    hash_with_dst_counted(message.as_ref(), dst).map(|(p, _)| p)
+}
+
+/// hash_to_curve that also reports the try-and-increment nonce n that produced the point.
+/// Output:
+/// - (H(message), n), where n + 1 candidate hashes were tried
+pub fn hash_to_curve_counted<T: AsRef<[u8]>>(message: T) -> Result<(G1Point, u8), BLSError> {
+    hash_with_dst_counted(message.as_ref(), DEFAULT_DST)
+}
+
+/// Domain tag for proof-of-possession hashes, distinct from the message signing tag so a PoP
+/// can never be replayed as a message signature.
+pub const POP_DST: &[u8] = b"BLS-BN254-POP";
+
+/// H_pop(pk): hash a G2 public key's uncompressed bytes under POP_DST.
+pub(crate) fn hash_to_curve_pop(pk: &G2Point) -> Result<G1Point, BLSError> {
+    hash_with_dst_counted(&pk.0, POP_DST).map(|(p, _)| p)
+}
+
+/// Try-and-increment: sha256(tag || message || n) for n in 0..255, until the digest (reduced
+/// mod p) is the x coordinate of a G1 point.
+///
+/// Notes:
+/// - tag is dst itself for DEFAULT_DST and POP_DST, which keeps existing signatures and PoPs
+///   valid, and I2OSP(len(dst), 2) || dst for every other dst. The framed form always starts
+///   with a zero byte and both bare tags start with 'B', so the two forms never overlap.
+/// - A candidate survives the NORMALIZE_MODULUS rejection with probability ~0.945 and is a valid
+///   x with probability ~1/2, so each try succeeds with p ~0.47. All 255 failing has
+///   probability ~2^-235; HashToCurveError is unreachable in practice.
+/// - The counter stays one byte: widening it would change every hash and invalidate existing
+///   signatures. hash_to_curve_rfc9380 is the fixed-sequence alternative.
+/// - G1 has cofactor 1, so every decompressed point is already in the prime-order subgroup.
+/// - The number of tries depends on the message, so hashing secret messages leaks timing.
+fn hash_with_dst_counted(message: &[u8], dst: &[u8]) -> Result<(G1Point, u8), BLSError> {
+    try_and_increment(message, dst, solana_nostd_sha256::hashv)
+}
+
+/// The try-and-increment loop of hash_with_dst_counted, over any 32 byte hashv-style function.
+fn try_and_increment(
+    message: &[u8],
+    dst: &[u8],
+    hashv: fn(&[&[u8]]) -> [u8; 32],
+) -> Result<(G1Point, u8), BLSError> {
+    let len = u8::try_from(dst.len())
+        .map_err(|_| BLSError::InvalidLength { expected: 255, got: dst.len() })?;
+    let bare = dst == DEFAULT_DST || dst == POP_DST;
+    let prefix: &[u8] = if bare { &[] } else { &[0, len] };
     (0..255)
         .find_map(|n: u8| {
 
-            let hash = solana_nostd_sha256::hashv(&[
-                b"BLS-BN254-RO",
-                message.as_ref(),
+            let hash = hashv(&[
+                prefix,
+                dst,
+                message,
                 &[n]
             ]);
 
@@ -27,16 +120,282 @@ pub fn hash_to_curve<T: AsRef<[u8]>>(message: T) -> Result<G1Point, BLSError> {
             let modulus_ubig = hash_ubig % &MODULUS;
 
             match alt_bn128_g1_decompress(&modulus_ubig.to_be_bytes()) {
-                Ok(p) => Some(G1Point(p)),
+                Ok(p) => Some((G1Point(p), n)),
                 Err(_) => None,
             }
         })
         .ok_or(BLSError::HashToCurveError)
 }
 
+/// hash_to_curve_with_dst with keccak256 in place of SHA-256 (keccak feature).
+/// Output:
+/// - The first candidate x = keccak256(tag || message || n) (with the same tag framing,
+///   rejection and reduction) that is on the curve
+///
+/// Notes:
+/// - For interop with EVM contracts that hash with Solidity's keccak256. Signatures over this
+///   hash only verify with the _keccak verifiers.
+#[cfg(feature = "keccak")]
+pub fn hash_to_curve_keccak<T: AsRef<[u8]>>(message: T, dst: &[u8]) -> Result<G1Point, BLSError> {
+    try_and_increment(message.as_ref(), dst, crate::keccak::hashv).map(|(p, _)| p)
+}
+
+/// The keccak-256 hasher, i.e. hash_to_curve_keccak (keccak feature).
+///
+/// Notes:
+/// - This is the crate's own try-and-increment map with keccak256 as the expansion function. It
+///   is not byte-compatible with any particular Ethereum BLS library; it gives EVM verifiers a
+///   keccak-based option they can reimplement from this definition.
+#[cfg(feature = "keccak")]
+pub struct Keccak256;
+
+#[cfg(feature = "keccak")]
+impl HashToCurve for Keccak256 {
+    fn try_hash_to_curve(message: &[u8], dst: &[u8]) -> Result<G1Point, BLSError> {
+        hash_to_curve_keccak(message, dst)
+    }
+}
+
+/// Hash a message to a scalar in Fr, e.g. for DLEQ challenges or derived blinding factors.
+/// Input:
+/// - message: message bytes
+/// - dst: domain separation tag, at most 255 bytes
+///
+/// Output:
+/// - expand_message_xmd(SHA-256, message, dst, 48) as a big-endian integer, reduced mod r
+/// - Err(InvalidLength) if dst is longer than 255 bytes
+///
+/// Notes:
+/// - 48 bytes is 128 bits more than r, so the reduction's bias is below 2^-128 (RFC 9380's
+///   hash_to_field with k = 128). There's no retry loop; every message gets a scalar.
+/// - The result may be zero with negligible probability; callers that need a nonzero scalar
+///   should check Fr::is_zero.
+pub fn hash_to_scalar(message: &[u8], dst: &[u8]) -> Result<Fr, BLSError> {
+    if dst.len() > 255 {
+        return Err(BLSError::InvalidLength { expected: 255, got: dst.len() });
+    }
+    let uniform: [u8; 48] = expand_message_xmd(message, dst);
+    let k = UBig::from_be_bytes(&uniform) % UBig::from_be_bytes(&SCALAR_ORDER);
+
+    let be = k.to_be_bytes();
+    let mut out = [0u8; 32];
+    out[32 - be.len()..].copy_from_slice(&be);
+    Fr::from_be_bytes_checked(out)
+}
+
+/// Domain tag for min_pk-style message hashes into G2 (see hash_to_curve_g2).
+pub const MIN_PK_DST: &[u8] = b"BLS-BN254-G2-RO";
+
+/// Hash a message to G2 under a caller-chosen domain tag, for min_pk-style protocols where
+/// signatures live in G2. Off-chain only.
+/// Input:
+/// - message: message bytes
+/// - dst: domain separation tag, at most 255 bytes
+///
+/// Output:
+/// - A point in the prime-order G2 subgroup, never the identity
+/// - Err(InvalidLength) if dst is longer than 255 bytes
+///
+/// Notes:
+/// - Try-and-increment like the G1 map: for n in 0..255, x = (c0, c1) with
+///   c_i = sha256(I2OSP(len(dst), 2) || dst || message || n || i) reduced mod p, until x is the
+///   x coordinate of a curve point (taking the smaller y). The twist has a large cofactor, so
+///   the point is then multiplied into the subgroup with clear_cofactor.
+/// - Every tag is length-prefixed, MIN_PK_DST included, so tags can't collide with each other.
+/// - Not RFC 9380, and the number of tries depends on the message.
+#[cfg(not(target_os = "solana"))]
+pub fn hash_to_curve_g2(message: &[u8], dst: &[u8]) -> Result<G2Point, BLSError> {
+    use ark_ec::short_weierstrass::SWCurveConfig;
+    use ark_ff::PrimeField;
+
+    let len = u8::try_from(dst.len())
+        .map_err(|_| BLSError::InvalidLength { expected: 255, got: dst.len() })?;
+    let prefix = [0, len];
+    (0..255)
+        .find_map(|n: u8| {
+            let c0 = solana_nostd_sha256::hashv(&[&prefix, dst, message, &[n], &[0]]);
+            let c1 = solana_nostd_sha256::hashv(&[&prefix, dst, message, &[n], &[1]]);
+            let x = ark_bn254::Fq2::new(
+                ark_bn254::Fq::from_be_bytes_mod_order(&c0),
+                ark_bn254::Fq::from_be_bytes_mod_order(&c1),
+            );
+            let p = ark_bn254::G2Affine::get_point_from_x_unchecked(x, false)?;
+            let q = ark_bn254::g2::Config::clear_cofactor(&p);
+            if q.infinity {
+                return None;
+            }
+            G2Point::from_ark(&q).ok()
+        })
+        .ok_or(BLSError::HashToCurveError)
+}
+
+/// RFC 9380 hash_to_curve for BN254 G1 (suite BN254G1_XMD:SHA-256_SVDW_RO_).
+/// Input:
+/// - message: message bytes
+/// - dst: domain separation tag, at most 255 bytes
+///
+/// Output:
+/// - map(u0) + map(u1), where u0, u1 = hash_to_field(message, 2) via expand_message_xmd with
+///   SHA-256, and map is the Shallue-van de Woestijne method (BN254 has a = 0, so simplified
+///   SWU would need an isogeny that doesn't exist for this curve)
+/// - Err(InvalidLength) if dst is longer than 255 bytes
+///
+/// Notes:
+/// - Matches other RFC 9380 BN254 implementations (e.g. gnark-crypto) under the same DST, but
+///   not hash_to_curve: signatures over one aren't valid over the other.
+/// - Every message runs the same sequence of field operations (no retry loop), so the running
+///   time doesn't reveal anything like a try count. The big-integer arithmetic underneath is
+///   not constant-time at the instruction level.
+/// - G1 has cofactor 1, so clear_cofactor is the identity map.
+/// - Works on-chain, at a higher compute cost than hash_to_curve (several modular
+///   exponentiations per map).
+pub fn hash_to_curve_rfc9380(message: &[u8], dst: &[u8]) -> Result<G1Point, BLSError> {
+    if dst.len() > 255 {
+        return Err(BLSError::InvalidLength { expected: 255, got: dst.len() });
+    }
+    let uniform: [u8; 96] = expand_message_xmd(message, dst);
+
+    let ring = ConstDivisor::new(MODULUS.clone());
+    let u0 = ring.reduce(UBig::from_be_bytes(&uniform[..48]));
+    let u1 = ring.reduce(UBig::from_be_bytes(&uniform[48..]));
+    g1_add(map_to_curve_svdw(&ring, &u0)?, map_to_curve_svdw(&ring, &u1)?)
+}
+
+/// expand_message_xmd (RFC 9380 section 5.3.1) with SHA-256, for a fixed output length N.
+/// dst must be at most 255 bytes and N at most 255 * 32.
+fn expand_message_xmd<const N: usize>(message: &[u8], dst: &[u8]) -> [u8; N] {
+    let dst_len = [dst.len() as u8];
+    let len = (N as u16).to_be_bytes();
+    // Z_pad || msg || I2OSP(N, 2) || I2OSP(0, 1) || DST_prime
+    let b0 = solana_nostd_sha256::hashv(&[&[0u8; 64], message, &len, &[0], dst, &dst_len]);
+
+    let mut out = [0u8; N];
+    let mut prev = solana_nostd_sha256::hashv(&[&b0, &[1], dst, &dst_len]);
+    for (i, chunk) in out.chunks_mut(32).enumerate() {
+        if i > 0 {
+            let mut mixed = b0;
+            for (m, p) in mixed.iter_mut().zip(prev.iter()) {
+                *m ^= p;
+            }
+            prev = solana_nostd_sha256::hashv(&[&mixed, &[i as u8 + 1], dst, &dst_len]);
+        }
+        chunk.copy_from_slice(&prev[..chunk.len()]);
+    }
+    out
+}
+
+/// Constants of the SvdW map for y^2 = x^3 + 3 with Z = 1 (RFC 9380 section 6.6.1):
+/// c1 = g(Z) = 4, c2 = -Z / 2, c3 = sqrt(-g(Z) * 3Z^2) with sgn0(c3) = 0, c4 = -4g(Z) / 3Z^2.
+const SVDW_C2: [u8; 32] = [
+    0x18, 0x32, 0x27, 0x39, 0x70, 0x98, 0xd0, 0x14, 0xdc, 0x28, 0x22, 0xdb, 0x40, 0xc0, 0xac, 0x2e,
+    0xcb, 0xc0, 0xb5, 0x48, 0xb4, 0x38, 0xe5, 0x46, 0x9e, 0x10, 0x46, 0x0b, 0x6c, 0x3e, 0x7e, 0xa3,
+];
+const SVDW_C3: [u8; 32] = [
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x67, 0x89, 0xaf, 0x3a, 0x83, 0x52, 0x2e, 0xb3,
+    0x53, 0xc9, 0x8f, 0xc6, 0xb3, 0x6d, 0x71, 0x3d, 0x5d, 0x8d, 0x1c, 0xc5, 0xdf, 0xff, 0xff, 0xfa,
+];
+const SVDW_C4: [u8; 32] = [
+    0x10, 0x21, 0x6f, 0x7b, 0xa0, 0x65, 0xe0, 0x0d, 0xe8, 0x1a, 0xc1, 0xe7, 0x80, 0x80, 0x72, 0xc9,
+    0xdd, 0x2b, 0x23, 0x85, 0xcd, 0x7b, 0x43, 0x84, 0x69, 0x60, 0x2e, 0xb2, 0x48, 0x29, 0xa9, 0xbd,
+];
+
+/// map_to_curve_svdw, following the straight-line steps of RFC 9380 appendix F.1.
+fn map_to_curve_svdw<'a>(ring: &'a ConstDivisor, u: &Reduced<'a>) -> Result<G1Point, BLSError> {
+    let fe = |bytes: &[u8]| ring.reduce(UBig::from_be_bytes(bytes));
+    let one = ring.reduce(1u8);
+    let b = ring.reduce(3u8);
+    let c1 = ring.reduce(4u8);
+    let (c2, c3, c4) = (fe(&SVDW_C2), fe(&SVDW_C3), fe(&SVDW_C4));
+
+    // inv0 and sqrt by exponentiation, since p = 3 mod 4
+    let p_minus_two = &MODULUS - UBig::from(2u8);
+    let legendre_exp = (&MODULUS - UBig::ONE) >> 1;
+    let sqrt_exp = (&MODULUS + UBig::ONE) >> 2;
+    let minus_one = -one.clone();
+    let is_square = |x: &Reduced<'a>| x.pow(&legendre_exp) != minus_one;
+    let g = |x: &Reduced<'a>| x * x * x + &b;
+
+    let mut tv1 = u * u * &c1;
+    let tv2 = &one + &tv1;
+    tv1 = &one - &tv1;
+    let tv3 = (&tv1 * &tv2).pow(&p_minus_two);
+    let tv4 = u * &tv1 * &tv3 * &c3;
+
+    let x1 = &c2 - &tv4;
+    let e1 = is_square(&g(&x1));
+    let x2 = &c2 + &tv4;
+    let e2 = is_square(&g(&x2)) && !e1;
+    let x3 = {
+        let t = &tv2 * &tv2 * &tv3;
+        &t * &t * &c4 + &one
+    };
+
+    let x = if e1 {
+        x1
+    } else if e2 {
+        x2
+    } else {
+        x3
+    };
+    let mut y = g(&x).pow(&sqrt_exp);
+    if u.residue().bit(0) != y.residue().bit(0) {
+        y = -y;
+    }
+
+    let to_bytes = |v: &Reduced<'a>| {
+        let be = v.residue().to_be_bytes();
+        let mut out = [0u8; 32];
+        out[32 - be.len()..].copy_from_slice(&be);
+        out
+    };
+    G1Point::from_affine_coords(&to_bytes(&x), &to_bytes(&y))
+        .map_err(|_| BLSError::HashToCurveError)
+}
+
+/// Frame a label and a message into one unambiguous hash input:
+/// len(label) as 4 bytes big-endian || label || message.
+fn frame_context(label: &[u8], message: &[u8]) -> Vec<u8> {
+    let mut m = Vec::with_capacity(4 + label.len() + message.len());
+    m.extend_from_slice(&(label.len() as u32).to_be_bytes());
+    m.extend_from_slice(label);
+    m.extend_from_slice(message);
+    m
+}
+
+/// Domain tag for context-bound signatures (PrivKey::sign_context).
+pub const CONTEXT_DOMAIN: &[u8] = b"BLS-BN254-CTX";
+
+/// H(message) for a context-bound signature:
+/// hash_to_curve_with_dst(frame_context(context, message), CONTEXT_DOMAIN).
+pub(crate) fn hash_context(context: &[u8], message: &[u8]) -> Result<G1Point, BLSError> {
+    hash_to_curve_with_dst(frame_context(context, message), CONTEXT_DOMAIN)
+}
+
+/// Domain tag that keeps AAD-bound signatures apart from plain and context signatures.
+pub const AAD_DOMAIN: &[u8] = b"BLS-BN254-AAD";
+
+/// H(message) for an AAD-bound signature:
+/// hash_to_curve_with_dst(frame_context(aad, message), AAD_DOMAIN).
+pub(crate) fn hash_aad(message: &[u8], aad: &[u8]) -> Result<G1Point, BLSError> {
+    hash_to_curve_with_dst(frame_context(aad, message), AAD_DOMAIN)
+}
+
+/// Domain tag for signatures that only become valid from a given epoch.
+pub const EPOCH_DOMAIN: &[u8] = b"BLS-BN254-VALID-FROM";
+
+/// H(message) for a signature valid from `epoch`:
+/// hash_to_curve_with_dst(epoch as 8 bytes big-endian || message, EPOCH_DOMAIN).
+pub(crate) fn hash_epoch(epoch: u64, message: &[u8]) -> Result<G1Point, BLSError> {
+    let mut m = Vec::with_capacity(8 + message.len());
+    m.extend_from_slice(&epoch.to_be_bytes());
+    m.extend_from_slice(message);
+    hash_to_curve_with_dst(m, EPOCH_DOMAIN)
+}
+
 #[cfg(test)]
 mod tests {
     use super::hash_to_curve;
+    use crate::errors::BLSError;
     use crate::g1::{G1CompressedPoint, G1Point};
 
     #[test]
@@ -47,11 +406,62 @@ mod tests {
         assert_eq!(h1.0, h2.0);
     }
 
+    #[test]
+    fn hash_to_curve_never_fails_on_random_messages() {
+        use rand::RngCore;
+        let mut rng = rand::thread_rng();
+        let mut msg = [0u8; 32];
+        for _ in 0..10_000 {
+            rng.fill_bytes(&mut msg);
+            assert!(hash_to_curve(msg).is_ok());
+        }
+    }
+
+    #[test]
+    fn hash_to_curve_with_dst_separates_domains() {
+        use super::{hash_to_curve_with_dst, DEFAULT_DST};
+        let m = b"domain-separation";
+        let a = hash_to_curve_with_dst(m, b"A").expect("a");
+        assert!(hash_to_curve_with_dst("domain-separation", b"A").expect("str") == a);
+        let b = hash_to_curve_with_dst(m, b"B").expect("b");
+        assert!(a != b);
+        let default = hash_to_curve_with_dst(m, DEFAULT_DST).expect("default");
+        assert!(default == hash_to_curve(m).expect("hash"));
+    }
+
+    #[test]
+    fn hash_to_curve_with_dst_frames_prefix_tags() {
+        use super::{hash_to_curve_g2, hash_to_curve_pop, hash_to_curve_with_dst, MIN_PK_DST};
+        use crate::g2::G2Point;
+
+        // A tag that is a prefix of a bare tag must not reproduce its hashes
+        let m = b"message";
+        let shifted = [&b"O"[..], m].concat();
+        let h = hash_to_curve_with_dst(&shifted, b"BLS-BN254-R").expect("prefix tag");
+        assert!(h != hash_to_curve(m).expect("default"));
+
+        let pk = G2Point::generator();
+        let shifted = [&b"P"[..], &pk.0].concat();
+        let h = hash_to_curve_with_dst(&shifted, b"BLS-BN254-PO").expect("prefix tag");
+        assert!(h != hash_to_curve_pop(&pk).expect("pop"));
+
+        // Likewise between two framed tags, and in G2
+        let a = hash_to_curve_with_dst(b"B-m", b"APP-A").expect("a");
+        assert!(a != hash_to_curve_with_dst(b"m", b"APP-AB-").expect("ab"));
+        let shifted = [&b"O"[..], m].concat();
+        let g2 = hash_to_curve_g2(&shifted, b"BLS-BN254-G2-R").expect("prefix tag");
+        assert_ne!(g2, hash_to_curve_g2(m, MIN_PK_DST).expect("min pk"));
+
+        let too_long = BLSError::InvalidLength { expected: 255, got: 256 };
+        assert_eq!(hash_to_curve_with_dst(m, &[b'A'; 256]).unwrap_err(), too_long);
+        assert_eq!(hash_to_curve_g2(m, &[b'A'; 256]).unwrap_err(), too_long);
+    }
+
     #[test]
     fn hash_to_curve_compress_decompress_roundtrip() {
         let m = b"hash-roundtrip";
         let h = hash_to_curve(m).expect("hash");
-        let hc = G1CompressedPoint::try_from(h.clone()).expect("compress");
+        let hc = G1CompressedPoint::try_from(h).expect("compress");
         let rt = G1Point::try_from(&hc).expect("decompress");
         assert_eq!(h.0, rt.0);
     }
@@ -62,4 +472,140 @@ mod tests {
         let h2 = hash_to_curve(b"m2").expect("h2");
         assert_ne!(h1.0, h2.0);
     }
+
+    fn hex32(s: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        for (i, b) in out.iter_mut().enumerate() {
+            *b = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    /// External reference: the BN254 G1 hash-to-curve vectors from gnark-crypto
+    /// (ecc/bn254, suite QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_), i.e. RFC 9380
+    /// expand_message_xmd + the Shallue-van de Woestijne map, since BN254 has no SSWU isogeny.
+    /// (message, x, y) with big-endian affine coordinates.
+    const RFC9380_SVDW_VECTORS: [(&[u8], &str, &str); 3] = [
+        (
+            b"",
+            "0a976ab906170db1f9638d376514dbf8c42aef256a54bbd48521f20749e59e86",
+            "02925ead66b9e68bfc309b014398640ab55f6619ab59bc1fab2210ad4c4d53d5",
+        ),
+        (
+            b"abc",
+            "23f717bee89b1003957139f193e6be7da1df5f1374b26a4643b0378b5baf53d1",
+            "04142f826b71ee574452dbc47e05bc3e1a647478403a7ba38b7b93948f4e151d",
+        ),
+        (
+            b"abcdef0123456789",
+            "187dbf1c3c89aceceef254d6548d7163fdfa43084145f92c4c91c85c21442d4a",
+            "0abd99d5b0000910b56058f9cc3b0ab0a22d47cf27615f588924fac1e5c63b4d",
+        ),
+    ];
+
+    /// This crate's try-and-increment outputs for the same messages, pinned so any change to
+    /// the map (and so to every signature it produces) shows up as a test failure.
+    const TRY_AND_INCREMENT_VECTORS: [(&[u8], &str, &str); 3] = [
+        (
+            b"",
+            "0104f3e0df9e98a0ab339d2b49fb736180b7d5ffd75ef22c283d230fff48c793",
+            "0550cc06e505b92db4d800f18672ff4064db14164ee84480dc3fdba783aa2dbc",
+        ),
+        (
+            b"abc",
+            "03622899c609f236c1ce90447d0f1808a4826e3eedce595d2dfcaeffe5a348f3",
+            "0517a3fd5f726d775fa02bb23db30a825d87f9b7d5b20db7c04acf9078f52fe4",
+        ),
+        (
+            b"abcdef0123456789",
+            "09f03dfcc29ceb90fb48c710163ba0c387763980ae8a3b302cd3cd9833bee45b",
+            "14e7baa2fe9790265eab92d6031f2bb7690121a1af8b194c9ad3b5b691138f1a",
+        ),
+    ];
+
+    #[test]
+    fn hash_to_curve_matches_pinned_vectors() {
+        for (m, x, y) in TRY_AND_INCREMENT_VECTORS {
+            let expected = G1Point::from_affine_coords(&hex32(x), &hex32(y)).expect("on curve");
+            assert_eq!(hash_to_curve(m).expect("hash").0, expected.0);
+        }
+    }
+
+    #[test]
+    fn hash_to_curve_is_not_rfc9380_compatible() {
+        // The reference points are valid G1 points (checks the transcription)...
+        for (m, x, y) in RFC9380_SVDW_VECTORS {
+            let reference = G1Point::from_affine_coords(&hex32(x), &hex32(y)).expect("on curve");
+
+            // ...but the bespoke try-and-increment map doesn't produce them, so signatures
+            // from this crate don't interoperate with RFC 9380 BN254 implementations.
+            assert_ne!(hash_to_curve(m).expect("hash").0, reference.0);
+        }
+    }
+
+    #[test]
+    fn hash_to_curve_rfc9380_matches_gnark_vectors() {
+        use super::hash_to_curve_rfc9380;
+        let dst = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_";
+        for (m, x, y) in RFC9380_SVDW_VECTORS {
+            let reference = G1Point::from_affine_coords(&hex32(x), &hex32(y)).expect("on curve");
+            assert_eq!(hash_to_curve_rfc9380(m, dst).expect("hash"), reference);
+        }
+
+        assert!(hash_to_curve_rfc9380(b"abc", &[0u8; 255]).is_ok());
+        let too_long = BLSError::InvalidLength { expected: 255, got: 256 };
+        assert_eq!(hash_to_curve_rfc9380(b"abc", &[0u8; 256]).unwrap_err(), too_long);
+    }
+
+    #[test]
+    fn hash_to_curve_g2_lands_in_subgroup() {
+        use super::hash_to_curve_g2;
+        let a = hash_to_curve_g2(b"g2-message", b"APP-G2").expect("hash");
+        let b = hash_to_curve_g2(b"g2-message", b"APP-G2").expect("hash");
+        assert_eq!(a, b);
+        assert_ne!(a, hash_to_curve_g2(b"g2-message", b"APP-G2-OTHER").expect("hash"));
+
+        let p = a.to_ark().expect("on curve");
+        assert!(p.is_on_curve() && p.is_in_correct_subgroup_assuming_on_curve());
+        assert_ne!(a.0, [0u8; 128]);
+        assert!(a.is_in_subgroup());
+    }
+
+    #[test]
+    fn hash_to_scalar_is_separated_and_reduced() {
+        use super::hash_to_scalar;
+        use crate::consts::SCALAR_ORDER;
+
+        let a = hash_to_scalar(b"scalar", b"A").expect("a");
+        // Cross-checked against an independent expand_message_xmd implementation
+        assert_eq!(a.0, hex32("17f9a58016039fc6c9e7ac49cc884d531ee81df13694212193864437eacffa85"));
+        assert!(a == hash_to_scalar(b"scalar", b"A").expect("a again"));
+        assert!(a != hash_to_scalar(b"scalar", b"B").expect("b"));
+        for i in 0u32..256 {
+            let k = hash_to_scalar(&i.to_be_bytes(), b"RANGE").expect("k");
+            assert!(k.0 < SCALAR_ORDER);
+        }
+        let too_long = BLSError::InvalidLength { expected: 255, got: 256 };
+        assert_eq!(hash_to_scalar(b"scalar", &[0u8; 256]).err(), Some(too_long));
+    }
+
+    #[cfg(feature = "keccak")]
+    #[test]
+    fn keccak256_hasher_is_pinned() {
+        use super::{hash_to_curve_keccak, HashToCurve, Keccak256, Sha256, DEFAULT_DST};
+
+        let h = Keccak256::try_hash_to_curve(b"abc", DEFAULT_DST).expect("keccak hash");
+        // Pinned like TRY_AND_INCREMENT_VECTORS, so a change to the keccak map shows up here
+        assert_eq!(
+            h.0[..32],
+            hex32("044e87fecbfc0da4dc084e14fd45b75f298b2b3a0f53fa520aae0f3cd96e4750")
+        );
+        assert_eq!(
+            h.0[32..],
+            hex32("136df7f109ea0532c1d1332654004d76723ab9464b660a0feb742bf0c76a1cb4")
+        );
+        assert!(h == hash_to_curve_keccak(b"abc", DEFAULT_DST).expect("free fn"));
+        assert!(h != Sha256::try_hash_to_curve(b"abc", DEFAULT_DST).expect("sha256 hash"));
+        assert!(h.is_on_curve());
+    }
 }
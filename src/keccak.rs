@@ -0,0 +1,163 @@
+// Keccak-256 as used by Ethereum (original Keccak padding, not NIST SHA3-256).
+//
+// Only compiled with the keccak feature, for hashing that has to match Solidity's keccak256.
+// On-chain hashv is the sol_keccak256 syscall, like solana_nostd_sha256 does for SHA-256. Host
+// builds use the small self-contained permutation below, pinned by known-answer vectors, so the
+// feature doesn't pull in another dependency.
+
+#[cfg(target_os = "solana")]
+extern "C" {
+    fn sol_keccak256(vals: *const u8, val_len: u64, hash_result: *mut u8) -> u64;
+}
+
+/// keccak256 over the concatenation of `parts`, mirroring solana_nostd_sha256::hashv.
+#[cfg(target_os = "solana")]
+pub(crate) fn hashv(parts: &[&[u8]]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    // SAFETY: the syscall reads parts.len() (ptr, len) slice descriptors and writes 32 bytes
+    unsafe {
+        sol_keccak256(parts as *const _ as *const u8, parts.len() as u64, out.as_mut_ptr());
+    }
+    out
+}
+
+#[cfg(not(target_os = "solana"))]
+const ROUND_CONSTANTS: [u64; 24] = [
+    0x0000000000000001, 0x0000000000008082, 0x800000000000808a, 0x8000000080008000,
+    0x000000000000808b, 0x0000000080000001, 0x8000000080008081, 0x8000000000008009,
+    0x000000000000008a, 0x0000000000000088, 0x0000000080008009, 0x000000008000000a,
+    0x000000008000808b, 0x800000000000008b, 0x8000000000008089, 0x8000000000008003,
+    0x8000000000008002, 0x8000000000000080, 0x000000000000800a, 0x800000008000000a,
+    0x8000000080008081, 0x8000000000008080, 0x0000000080000001, 0x8000000080008008,
+];
+
+#[cfg(not(target_os = "solana"))]
+const ROTATIONS: [u32; 24] = [
+    1, 3, 6, 10, 15, 21, 28, 36, 45, 55, 2, 14, 27, 41, 56, 8, 25, 43, 62, 18, 39, 61, 20, 44,
+];
+
+#[cfg(not(target_os = "solana"))]
+const PI_LANES: [usize; 24] = [
+    10, 7, 11, 17, 18, 3, 5, 16, 8, 21, 24, 4, 15, 23, 19, 13, 12, 2, 20, 14, 22, 9, 6, 1,
+];
+
+/// Rate of Keccak-256 in bytes (1600 - 2 * 256 bits).
+#[cfg(not(target_os = "solana"))]
+const RATE: usize = 136;
+
+#[cfg(not(target_os = "solana"))]
+fn keccak_f(state: &mut [u64; 25]) {
+    for rc in ROUND_CONSTANTS {
+        // Theta
+        let mut c = [0u64; 5];
+        for (x, lane) in c.iter_mut().enumerate() {
+            *lane = state[x] ^ state[x + 5] ^ state[x + 10] ^ state[x + 15] ^ state[x + 20];
+        }
+        for x in 0..5 {
+            let d = c[(x + 4) % 5] ^ c[(x + 1) % 5].rotate_left(1);
+            for y in 0..5 {
+                state[5 * y + x] ^= d;
+            }
+        }
+
+        // Rho and pi
+        let mut carry = state[1];
+        for (&lane, &rot) in PI_LANES.iter().zip(ROTATIONS.iter()) {
+            let next = state[lane];
+            state[lane] = carry.rotate_left(rot);
+            carry = next;
+        }
+
+        // Chi
+        for y in 0..5 {
+            let row = [
+                state[5 * y],
+                state[5 * y + 1],
+                state[5 * y + 2],
+                state[5 * y + 3],
+                state[5 * y + 4],
+            ];
+            for x in 0..5 {
+                state[5 * y + x] ^= !row[(x + 1) % 5] & row[(x + 2) % 5];
+            }
+        }
+
+        // Iota
+        state[0] ^= rc;
+    }
+}
+
+#[cfg(not(target_os = "solana"))]
+fn absorb(block: &[u8; RATE], state: &mut [u64; 25]) {
+    for (lane, bytes) in state.iter_mut().zip(block.chunks_exact(8)) {
+        *lane ^= u64::from_le_bytes(bytes.try_into().unwrap());
+    }
+    keccak_f(state);
+}
+
+/// keccak256 over the concatenation of `parts`, mirroring solana_nostd_sha256::hashv.
+#[cfg(not(target_os = "solana"))]
+pub(crate) fn hashv(parts: &[&[u8]]) -> [u8; 32] {
+    let mut state = [0u64; 25];
+    let mut block = [0u8; RATE];
+    let mut filled = 0;
+
+    for &byte in parts.iter().flat_map(|p| p.iter()) {
+        block[filled] = byte;
+        filled += 1;
+        if filled == RATE {
+            absorb(&block, &mut state);
+            filled = 0;
+        }
+    }
+
+    // Keccak padding: 0x01 .. 0x80 (SHA3 would use 0x06)
+    block[filled..].fill(0);
+    block[filled] ^= 0x01;
+    block[RATE - 1] ^= 0x80;
+    absorb(&block, &mut state);
+
+    let mut out = [0u8; 32];
+    for (bytes, lane) in out.chunks_exact_mut(8).zip(state.iter()) {
+        bytes.copy_from_slice(&lane.to_le_bytes());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::hashv;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|b| format!("{:02x}", b)).collect()
+    }
+
+    #[test]
+    fn keccak256_known_vectors() {
+        assert_eq!(
+            hex(&hashv(&[])),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+        assert_eq!(
+            hex(&hashv(&[b"abc"])),
+            "4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45"
+        );
+        // Lengths around the 136 byte rate (padding in the same block, a padding-only block, and
+        // one byte into the second block) plus multi-block inputs, all of b'a', cross-checked
+        // against OpenSSL's KECCAK-256
+        let vectors = [
+            (135, "34367dc248bbd832f4e3e69dfaac2f92638bd0bbd18f2912ba4ef454919cf446"),
+            (136, "a6c4d403279fe3e0af03729caada8374b5ca54d8065329a3ebcaeb4b60aa386e"),
+            (137, "d869f639c7046b4929fc92a4d988a8b22c55fbadb802c0c66ebcd484f1915f39"),
+            (272, "cf7fcd4f705ee749930d19ca84561a9bf62516bd90a471545fa2f49fdc7e63c8"),
+            (300, "5b7e0e47a96f32a88b4f14ca177982790807c40e1a105742ba0fc1babe1ef826"),
+        ];
+        let long = [0x61u8; 300];
+        for (len, expected) in vectors {
+            assert_eq!(hex(&hashv(&[&long[..len]])), expected, "len {}", len);
+        }
+
+        // Split input hashes the same as the concatenation
+        assert_eq!(hashv(&[&long[..100], &long[100..]]), hashv(&[&long]));
+    }
+}
@@ -1,14 +1,27 @@
 #![allow(unexpected_cfgs)]
 
+pub mod bn254;
 pub mod consts;
 pub mod errors;
 pub mod g1;
 pub mod g2;
 pub mod hash;
+#[cfg(feature = "keccak")]
+mod keccak;
+#[cfg(not(target_os = "solana"))]
+pub mod min_pk;
 pub mod privkey;
+pub mod scalar;
+#[cfg(test)]
+pub(crate) mod test_utils;
+pub mod threshold;
 pub mod utils;
 
+pub use crate::consts::{G1_GENERATOR, G1_MINUS_ONE, G2_GENERATOR, G2_MINUS_ONE};
 pub use crate::g1::{G1CompressedPoint, G1Point};
-pub use crate::g2::{G2CompressedPoint, G2Point};
+pub use crate::g2::{
+    prove_possession, verify_possession, verify_possession_batch, G2CompressedPoint, G2Point,
+};
 pub use crate::privkey::PrivKey;
-pub use crate::utils::{verify_augmented, verify_fast_aggregate};
+pub use crate::scalar::Fr;
+pub use crate::utils::{verify_augmented, verify_fast_aggregate, verify_single};
@@ -0,0 +1,10 @@
+// The min_pk flavor of BLS: public keys in G1 (32 bytes compressed) and signatures in G2.
+// Hashing to G2 and summing G2 points need arkworks, so everything here is off-chain only.
+// Messages hash with hash::hash_to_curve_g2 under hash::MIN_PK_DST.
+
+pub mod threshold;
+
+pub use crate::min_pk::threshold::{
+    bls_partial_sign_min_pk, verify_a1_min_pk_with_indices, G1PubkeyProvider, SliceG1PubkeyProvider,
+};
+pub use crate::utils::{aggregate_g2_partials, verify_fast_aggregate_min_pk};
@@ -0,0 +1,177 @@
+// Threshold verification for the min_pk flavor, mirroring crate::threshold with G1 keys from a
+// G1PubkeyProvider and G2 aggregates summed with aggregate_g2_partials.
+
+use crate::bn254::pairing;
+use crate::errors::BLSError;
+use crate::g1::G1Point;
+use crate::g2::G2Point;
+use crate::hash::{hash_to_curve_g2, MIN_PK_DST};
+use crate::privkey::{check_security_acknowledged, check_signing_scalar};
+use crate::scalar::Fr;
+use crate::threshold::check_no_duplicate_indices;
+
+pub use crate::utils::aggregate_g2_partials;
+
+/// Source of committee G1 public keys by index.
+pub trait G1PubkeyProvider {
+    /// Return the G1 public key of committee member `index`.
+    fn g1_by_index(&self, index: u16) -> Result<G1Point, BLSError>;
+}
+
+/// Committee G1 keys held in a slice.
+pub struct SliceG1PubkeyProvider<'a>(pub &'a [G1Point]);
+
+impl G1PubkeyProvider for SliceG1PubkeyProvider<'_> {
+    fn g1_by_index(&self, index: u16) -> Result<G1Point, BLSError> {
+        self.0
+            .get(index as usize)
+            .copied()
+            .ok_or(BLSError::IndexOutOfBounds)
+    }
+}
+
+/// Compute a min_pk partial signature in G2.
+/// Input:
+/// - sk: secret scalar
+/// - message: message bytes
+///
+/// Output:
+/// - S_i = sk_i * H_G2(message), for the public key sk_i * G1
+///
+/// Notes:
+/// - Same PoP caveat as utils::bls_partial_sign: the fast-path check is only safe if every
+///   committee key has a proof of possession.
+pub fn bls_partial_sign_min_pk(sk: &Fr, message: impl AsRef<[u8]>) -> Result<G2Point, BLSError> {
+    check_security_acknowledged()?;
+    check_signing_scalar(&sk.0)?;
+    hash_to_curve_g2(message.as_ref(), MIN_PK_DST)?.scalar_mul(&sk.0)
+}
+
+/// A1 threshold verify over an explicit list of signer indices, for the min_pk flavor.
+/// Input:
+/// - message: message bytes
+/// - signer_indices: committee indices of the members that signed
+/// - s_sum: aggregated G2 signature = sum of the signers' partial signatures
+/// - pk_provider: committee G1 public keys
+///
+/// Output:
+/// - Ok if the aggregate verifies for exactly these signers, Err otherwise
+///
+/// Notes:
+/// - An empty list is rejected with EmptySignerSet, duplicate indices with DuplicateSigner, and
+///   an identity s_sum or signer key with PointAtInfinity.
+pub fn verify_a1_min_pk_with_indices<M: AsRef<[u8]>>(
+    message: M,
+    signer_indices: &[u16],
+    s_sum: G2Point,
+    pk_provider: &impl G1PubkeyProvider,
+) -> Result<(), BLSError> {
+    if signer_indices.is_empty() {
+        return Err(BLSError::EmptySignerSet);
+    }
+    if !check_no_duplicate_indices(signer_indices) {
+        return Err(BLSError::DuplicateSigner);
+    }
+    if s_sum.is_identity() {
+        return Err(BLSError::PointAtInfinity);
+    }
+
+    let h_g2 = hash_to_curve_g2(message.as_ref(), MIN_PK_DST)?;
+
+    let mut pairs = Vec::with_capacity(signer_indices.len() + 1);
+    for &i in signer_indices {
+        let pk = pk_provider.g1_by_index(i)?;
+        if pk.is_identity() {
+            return Err(BLSError::PointAtInfinity);
+        }
+        pairs.push((pk, h_g2));
+    }
+    pairs.push((G1Point::neg_generator(), s_sum));
+
+    if pairing(&pairs)? {
+        Ok(())
+    } else {
+        Err(BLSError::BLSVerificationError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        aggregate_g2_partials,
+        bls_partial_sign_min_pk,
+        verify_a1_min_pk_with_indices,
+        G1PubkeyProvider,
+        SliceG1PubkeyProvider,
+    };
+    use crate::errors::BLSError;
+    use crate::g1::G1Point;
+    use crate::g2::G2Point;
+    use crate::privkey::PrivKey;
+    use crate::scalar::Fr;
+
+    fn committee(n: usize) -> (Vec<PrivKey>, Vec<G1Point>) {
+        let keys: Vec<PrivKey> = (0..n).map(|_| PrivKey::from_random()).collect();
+        let pks = keys.iter().map(|k| G1Point::try_from(PrivKey(k.0)).unwrap()).collect();
+        (keys, pks)
+    }
+
+    fn sign_with(keys: &[PrivKey], indices: &[u16], msg: &[u8]) -> G2Point {
+        let partials: Vec<G2Point> = indices
+            .iter()
+            .map(|&i| Fr::try_from(&keys[i as usize]).unwrap())
+            .map(|sk| bls_partial_sign_min_pk(&sk, msg).unwrap())
+            .collect();
+        aggregate_g2_partials(&partials).unwrap()
+    }
+
+    #[test]
+    fn a1_min_pk_75_of_100() {
+        let msg = b"a1-min-pk";
+        let (keys, pks) = committee(100);
+        let committee = SliceG1PubkeyProvider(&pks);
+        let indices: Vec<u16> = (0..100).filter(|i| i % 4 != 3).collect();
+        assert_eq!(indices.len(), 75);
+
+        let s_sum = sign_with(&keys, &indices, msg);
+        verify_a1_min_pk_with_indices(msg, &indices, s_sum, &committee).expect("a1 verify");
+
+        // Claiming a different signer set must fail
+        let mut other = indices.clone();
+        other[0] = 3;
+        let err = verify_a1_min_pk_with_indices(msg, &other, s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+
+        let err = verify_a1_min_pk_with_indices(b"other", &indices, s_sum, &committee);
+        assert_eq!(err.unwrap_err(), BLSError::BLSVerificationError);
+
+        // Duplicate indices must be rejected
+        let err = verify_a1_min_pk_with_indices(msg, &[0, 0, 1], s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::DuplicateSigner);
+    }
+
+    #[test]
+    fn a1_min_pk_rejects_identity() {
+        let msg = b"a1-min-pk-identity";
+        let (keys, mut pks) = committee(2);
+        let s_sum = sign_with(&keys, &[0], msg);
+
+        let committee = SliceG1PubkeyProvider(&pks);
+        let zero = G2Point([0u8; 128]);
+        let err = verify_a1_min_pk_with_indices(msg, &[0], zero, &committee).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+
+        pks[1] = G1Point::IDENTITY;
+        let committee = SliceG1PubkeyProvider(&pks);
+        let err = verify_a1_min_pk_with_indices(msg, &[0, 1], s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+    }
+
+    #[test]
+    fn slice_g1_provider_bounds() {
+        let (_, pks) = committee(2);
+        let provider = SliceG1PubkeyProvider(&pks);
+        assert_eq!(provider.g1_by_index(1).unwrap(), pks[1]);
+        assert_eq!(provider.g1_by_index(2).unwrap_err(), BLSError::IndexOutOfBounds);
+    }
+}
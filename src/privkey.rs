@@ -1,23 +1,106 @@
 #[cfg(not(target_os = "solana"))]
 use rand::RngCore;
 
-use solana_bn254::prelude::alt_bn128_multiplication;
-
 #[cfg(not(target_os = "solana"))]
 use crate::consts::MODULUS;
+use crate::consts::SCALAR_ORDER;
 
+use crate::bn254::g1_mul;
 use crate::errors::BLSError;
 use crate::g1::G1Point;
-use crate::hash::hash_to_curve;
+#[cfg(not(target_os = "solana"))]
+use crate::g2::G2Point;
+use crate::hash::{hash_aad, hash_context, hash_epoch, hash_to_curve};
 
 pub struct PrivKey(pub [u8; 32]);
 
+impl AsRef<[u8]> for PrivKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for PrivKey {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// Wipes the scalar when the key goes out of scope.
+/// Notes:
+/// - Copies made through the public field (e.g. PrivKey(sk.0)) are separate keys and are wiped
+///   by their own drop; plain [u8; 32] copies are not.
+impl Drop for PrivKey {
+    fn drop(&mut self) {
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut self.0);
+        #[cfg(not(feature = "zeroize"))]
+        wipe(&mut self.0);
+    }
+}
+
+/// Never prints the scalar, so keys can't leak through logs or panic messages.
+impl core::fmt::Debug for PrivKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "PrivKey(<redacted>)")
+    }
+}
+
+#[cfg(all(feature = "strict-security", not(target_os = "solana")))]
+static SECURITY_ACKNOWLEDGED: core::sync::atomic::AtomicBool =
+    core::sync::atomic::AtomicBool::new(false);
+
+/// Acknowledge BN254's security level before signing (strict-security feature only).
+/// Input:
+/// - min_bits: the security level the caller is willing to accept
+///
+/// Output:
+/// - Ok and signing is unlocked for the process if SECURITY_BITS >= min_bits,
+///   Err(BLSSigningError) otherwise
+///
+/// Notes:
+/// - With strict-security enabled, PrivKey::sign and the partial signing helpers fail with
+///   BLSSigningError until this has succeeded once.
+#[cfg(all(feature = "strict-security", not(target_os = "solana")))]
+pub fn acknowledge_security_level(min_bits: u32) -> Result<(), BLSError> {
+    if crate::consts::SECURITY_BITS < min_bits {
+        return Err(BLSError::BLSSigningError);
+    }
+    SECURITY_ACKNOWLEDGED.store(true, core::sync::atomic::Ordering::SeqCst);
+    Ok(())
+}
+
+/// Gate for every signing entry point; a no-op unless strict-security is enabled.
+pub(crate) fn check_security_acknowledged() -> Result<(), BLSError> {
+    #[cfg(all(test, feature = "strict-security"))]
+    crate::test_utils::acknowledge_security();
+    #[cfg(all(feature = "strict-security", not(target_os = "solana")))]
+    security_gate(SECURITY_ACKNOWLEDGED.load(core::sync::atomic::Ordering::SeqCst))?;
+    Ok(())
+}
+
+/// The strict-security decision itself, separate from the process-wide flag so it can be tested.
+#[cfg(all(feature = "strict-security", not(target_os = "solana")))]
+fn security_gate(acknowledged: bool) -> Result<(), BLSError> {
+    if !acknowledged {
+        return Err(BLSError::BLSSigningError);
+    }
+    Ok(())
+}
+
 impl PrivKey {
     #[cfg(not(target_os = "solana"))]
     pub fn from_random() -> PrivKey {
+        Self::from_rng(&mut rand::thread_rng())
+    }
+
+    /// from_random drawing from a caller-supplied RNG, e.g. a seeded one in tests or an
+    /// HSM-backed source.
+    #[cfg(not(target_os = "solana"))]
+    pub fn from_rng<R: RngCore + ?Sized>(rng: &mut R) -> PrivKey {
         loop {
             let mut bytes = [0u8; 32];
-            rand::thread_rng().fill_bytes(&mut bytes);
+            rng.fill_bytes(&mut bytes);
             let num = dashu::integer::UBig::from_be_bytes(&bytes);
             if num < MODULUS {
                 return Self(bytes);
@@ -25,17 +108,356 @@ impl PrivKey {
         }
     }
 
+    /// Build a key from 32 big-endian bytes, rejecting unusable scalars.
+    /// Output:
+    /// - Err(ScalarOutOfRange) if the scalar is zero or not below the group order r
+    ///
+    /// Notes:
+    /// - r is smaller than the base field modulus, so anything >= MODULUS is rejected too.
+    /// - Unlike building PrivKey(bytes) directly, this can't produce an identity or aliased key.
+    pub fn from_bytes(bytes: [u8; 32]) -> Result<PrivKey, BLSError> {
+        check_signing_scalar(&bytes)?;
+        Ok(PrivKey(bytes))
+    }
+
+    /// Derive the key at `index` from a master seed, deterministically.
+    /// Input:
+    /// - seed: at least 32 bytes of secret entropy (e.g. a BIP-39 seed)
+    /// - index: which key to derive, e.g. a committee member's slot
+    ///
+    /// Output:
+    /// - The KeyGen procedure of draft-irtf-cfrg-bls-signature with key_info = index as 4 bytes
+    ///   BE: HKDF-SHA256 with salt "BLS-SIG-KEYGEN-SALT-", 48 bytes of output reduced mod r,
+    ///   re-salting with sha256(salt) on the (negligible) chance the result is zero
+    /// - Err(InvalidLength) if the seed is shorter than 32 bytes
+    ///
+    /// Notes:
+    /// - Works on-chain, though keys should normally be derived off-chain.
+    /// - This is EIP-2333's leaf KeyGen step only; it does not implement the Lamport-based
+    ///   parent/child tree, so paths like m/12381/... are not supported.
+    pub fn derive_from_seed(seed: &[u8], index: u32) -> Result<PrivKey, BLSError> {
+        if seed.len() < 32 {
+            return Err(BLSError::InvalidLength { expected: 32, got: seed.len() });
+        }
+        let info = index.to_be_bytes();
+
+        let mut salt = solana_nostd_sha256::hashv(&[b"BLS-SIG-KEYGEN-SALT-"]);
+        loop {
+            // IKM || I2OSP(0, 1), key_info || I2OSP(48, 2)
+            let mut prk = hmac_sha256(&salt, &[seed, &[0u8]]);
+            let mut okm = [0u8; 48];
+            hkdf_expand(&prk, &[&info, &[0u8, 48]], &mut okm);
+            let sk = reduce_mod_r(&okm);
+            wipe(&mut prk);
+            wipe(&mut okm);
+
+            if sk != [0u8; 32] {
+                return Ok(PrivKey(sk));
+            }
+            salt = solana_nostd_sha256::hashv(&[&salt]);
+        }
+    }
+
+    /// Derive a key from input keying material with HKDF-SHA256 (RFC 5869).
+    /// Input:
+    /// - ikm: at least 32 bytes of secret keying material, e.g. a seed or master key
+    /// - salt: HKDF salt; empty is allowed and means the all-zero salt
+    /// - info: context that separates keys derived from the same ikm
+    ///
+    /// Output:
+    /// - HKDF-Expand(HKDF-Extract(salt, ikm), info, 48) as a big-endian integer, reduced mod r
+    /// - Err(InvalidLength) if ikm is shorter than 32 bytes, Err(ScalarOutOfRange) if the result
+    ///   is zero
+    ///
+    /// Notes:
+    /// - 48 bytes is 128 bits more than r, so the reduction's bias is below 2^-128, as in
+    ///   hash::hash_to_scalar. A zero result has probability ~2^-254.
+    /// - Unlike derive_from_seed, nothing is appended to ikm or info, so outputs are plain HKDF
+    ///   and can be reproduced by any HKDF-SHA256 implementation.
+    pub fn from_ikm(ikm: &[u8], salt: &[u8], info: &[u8]) -> Result<PrivKey, BLSError> {
+        if ikm.len() < 32 {
+            return Err(BLSError::InvalidLength { expected: 32, got: ikm.len() });
+        }
+        let mut prk = hmac_sha256(salt, &[ikm]);
+        let mut okm = [0u8; 48];
+        hkdf_expand(&prk, &[info], &mut okm);
+        let sk = reduce_mod_r(&okm);
+        wipe(&mut prk);
+        wipe(&mut okm);
+
+        if sk == [0u8; 32] {
+            return Err(BLSError::ScalarOutOfRange);
+        }
+        Ok(PrivKey(sk))
+    }
+
+    /// Derive the child key at `index` from this key, e.g. one per data feed or per epoch.
+    /// Output:
+    /// - PrivKey::from_ikm(HMAC-SHA256(sk, "bls-child" || index as 4 bytes BE), "",
+    ///   "brine-bn128-bls-child")
+    ///
+    /// Notes:
+    /// - HMAC is one-way, so neither the parent nor a sibling can be recovered from a child key.
+    /// - For a deeper hierarchy, call derive_child again on the child.
+    pub fn derive_child(&self, index: u32) -> Result<PrivKey, BLSError> {
+        let mut child_ikm = hmac_sha256(&self.0, &[b"bls-child", &index.to_be_bytes()]);
+        let child = PrivKey::from_ikm(&child_ikm, b"", b"brine-bn128-bls-child");
+        wipe(&mut child_ikm);
+        child
+    }
+
+    /// The canonical public key of this scheme: sk * G2.
+    /// Signatures from PrivKey::sign live in G1 and verify against this key.
+    /// The TryFrom<PrivKey> for G1Point derivation (sk * G1) is only for min_pk-style use where
+    /// the roles of the groups are swapped.
+    #[cfg(not(target_os = "solana"))]
+    pub fn pubkey_g2(&self) -> Result<G2Point, BLSError> {
+        G2Point::try_from(self)
+    }
+
+    /// Proof of possession for this key: sk * H_pop(pk), with pk = pubkey_g2().
+    /// Submit it alongside the public key at registration; check it with verify_possession.
+    #[cfg(not(target_os = "solana"))]
+    pub fn prove_possession(&self) -> Result<G1Point, BLSError> {
+        crate::g2::prove_possession(self, &self.pubkey_g2()?)
+    }
+
+    /// Full sign -> derive pubkey -> verify loop for this key, for key-management tooling.
+    #[cfg(not(target_os = "solana"))]
+    pub fn self_verify(&self, message: &[u8]) -> Result<(), BLSError> {
+        let sig = self.sign(message)?;
+        self.pubkey_g2()?.verify(&sig, message)
+    }
+
+    /// Borrow the raw scalar. Prefer this over `.0` so secret access is easy to grep for.
+    pub fn expose_secret(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Copy out the raw scalar. The copy isn't wiped on drop; prefer expose_secret where a
+    /// borrow will do.
+    pub fn as_bytes(&self) -> [u8; 32] {
+        self.0
+    }
+
+    /// Compare two keys in constant time.
+    /// Output:
+    /// - true if the scalars are equal
+    ///
+    /// Notes:
+    /// - Every byte is visited and differences are OR-ed together, so the running time doesn't
+    ///   depend on where the keys first differ.
+    pub fn ct_eq(&self, other: &PrivKey) -> bool {
+        ct_eq_bytes(&self.0, &other.0)
+    }
+
+    /// Encode the key as 64 lowercase hex characters (big-endian), for CLI tooling.
+    /// The returned string is the secret; the caller is responsible for disposing of it.
+    #[cfg(not(target_os = "solana"))]
+    pub fn to_hex(&self) -> String {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        let mut out = String::with_capacity(64);
+        for b in self.0 {
+            out.push(HEX[(b >> 4) as usize] as char);
+            out.push(HEX[(b & 0x0f) as usize] as char);
+        }
+        out
+    }
+
+    /// Parse a key from 64 hex characters (big-endian, either case, optional "0x" prefix).
+    /// Output:
+    /// - The key, Err(SecretKeyError) if the length or a digit is wrong, or
+    ///   Err(ScalarOutOfRange) if the value is zero or not below the scalar order r
+    ///
+    /// Notes:
+    /// - The decode buffer is wiped before returning on every path.
+    #[cfg(not(target_os = "solana"))]
+    pub fn from_hex(s: &str) -> Result<PrivKey, BLSError> {
+        let s = s.strip_prefix("0x").unwrap_or(s).as_bytes();
+        if s.len() != 64 {
+            return Err(BLSError::SecretKeyError);
+        }
+
+        let mut bytes = [0u8; 32];
+        let mut ok = true;
+        for (i, pair) in s.chunks_exact(2).enumerate() {
+            match (hex_digit(pair[0]), hex_digit(pair[1])) {
+                (Some(hi), Some(lo)) => bytes[i] = (hi << 4) | lo,
+                _ => ok = false,
+            }
+        }
+        let result = if !ok {
+            Err(BLSError::SecretKeyError)
+        } else if !is_valid_scalar(&bytes) {
+            Err(BLSError::ScalarOutOfRange)
+        } else {
+            Ok(PrivKey(bytes))
+        };
+        wipe(&mut bytes);
+        result
+    }
+
+    /// Sign `message` with this key.
+    /// Output:
+    /// - H(message) * sk in G1, or Err(ScalarOutOfRange) if the key is zero or not below r
     pub fn sign<T: AsRef<[u8]>>(&self, message: T) -> Result<G1Point, BLSError> {
-        let point = hash_to_curve(&message)?;
-        let input = [&point.0[..], &self.0[..]].concat();
+        self.sign_hash(hash_to_curve(&message)?)
+    }
 
-        let mut g1_sol_uncompressed = [0x00u8; 64];
-        g1_sol_uncompressed.clone_from_slice(
-            &alt_bn128_multiplication(&input).map_err(|_| BLSError::BLSSigningError)?,
-        );
+    /// h * sk, for the signing variants that hash under their own domain tag.
+    fn sign_hash(&self, h: G1Point) -> Result<G1Point, BLSError> {
+        check_security_acknowledged()?;
+        check_signing_scalar(&self.0)?;
+        g1_mul(h, &self.0).map_err(|_| BLSError::BLSSigningError)
+    }
+
+    /// Sign a message bound to a context label (e.g. a subsystem name).
+    /// A signature made under one context never verifies under another, so a single key can be
+    /// shared across subsystems. Verify with G2Point::verify_context.
+    pub fn sign_context(&self, context: &[u8], message: &[u8]) -> Result<G1Point, BLSError> {
+        self.sign_hash(hash_context(context, message)?)
+    }
+
+    /// Sign a message bound to associated data (AAD) that travels separately.
+    /// The AAD is not recoverable from the signature; the verifier supplies it independently
+    /// to G2Point::verify_with_aad, and verification fails unless it matches what was signed.
+    pub fn sign_with_aad(&self, message: &[u8], aad: &[u8]) -> Result<G1Point, BLSError> {
+        self.sign_hash(hash_aad(message, aad)?)
+    }
+
+    /// Sign a message that only becomes valid at or after `epoch`.
+    /// The epoch is folded into the hash domain; G2Point::verify_valid_from enforces it.
+    pub fn sign_valid_from(&self, epoch: u64, message: &[u8]) -> Result<G1Point, BLSError> {
+        self.sign_hash(hash_epoch(epoch, message)?)
+    }
+}
 
-        Ok(G1Point(g1_sol_uncompressed))
+/// A usable secret scalar is in 1..r.
+/// Fixed-width big-endian arrays compare like the integers they encode.
+fn is_valid_scalar(bytes: &[u8; 32]) -> bool {
+    *bytes != [0u8; 32] && *bytes < SCALAR_ORDER
+}
+
+/// Byte equality whose running time depends only on the lengths, not on where the inputs differ.
+/// Every byte is visited and differences are OR-ed together; the lengths themselves are public.
+pub(crate) fn ct_eq_bytes(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
     }
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        diff |= x ^ y;
+    }
+    core::hint::black_box(diff) == 0
+}
+
+/// Range check for signing scalars: zero would sign to the identity and r + k to the same
+/// signature as k, so either is Err(ScalarOutOfRange).
+pub(crate) fn check_signing_scalar(bytes: &[u8; 32]) -> Result<(), BLSError> {
+    if !is_valid_scalar(bytes) {
+        return Err(BLSError::ScalarOutOfRange);
+    }
+    Ok(())
+}
+
+/// Same comparison as the inherent ct_eq, returned as a subtle::Choice.
+#[cfg(feature = "subtle")]
+impl subtle::ConstantTimeEq for PrivKey {
+    fn ct_eq(&self, other: &Self) -> subtle::Choice {
+        self.0[..].ct_eq(&other.0[..])
+    }
+}
+
+/// Parse a 32 byte big-endian secret key from instruction data or storage.
+/// Output:
+/// - Err(InvalidLength) if the slice isn't 32 bytes,
+///   Err(ScalarOutOfRange) if the scalar is zero or not below r
+impl TryFrom<&[u8]> for PrivKey {
+    type Error = BLSError;
+
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 32] = value
+            .try_into()
+            .map_err(|_| BLSError::InvalidLength { expected: 32, got: value.len() })?;
+        if !is_valid_scalar(&bytes) {
+            return Err(BLSError::ScalarOutOfRange);
+        }
+        Ok(PrivKey(bytes))
+    }
+}
+
+#[cfg(not(target_os = "solana"))]
+fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// HMAC-SHA256 (RFC 2104) over the concatenation of `parts`.
+fn hmac_sha256(key: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let mut block = [0u8; 64];
+    if key.len() > 64 {
+        block[..32].copy_from_slice(&solana_nostd_sha256::hashv(&[key]));
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    let mut ipad = [0x36u8; 64];
+    let mut opad = [0x5cu8; 64];
+    for i in 0..64 {
+        ipad[i] ^= block[i];
+        opad[i] ^= block[i];
+    }
+
+    let mut inner_input: Vec<&[u8]> = Vec::with_capacity(parts.len() + 1);
+    inner_input.push(&ipad);
+    inner_input.extend_from_slice(parts);
+    let inner = solana_nostd_sha256::hashv(&inner_input);
+    let out = solana_nostd_sha256::hashv(&[&opad, &inner]);
+
+    wipe(&mut block);
+    wipe(&mut ipad);
+    wipe(&mut opad);
+    out
+}
+
+/// HKDF-Expand (RFC 5869) with SHA-256, `info` given as the concatenation of its parts.
+/// `out` must be at most 255 * 32 bytes.
+fn hkdf_expand(prk: &[u8; 32], info: &[&[u8]], out: &mut [u8]) {
+    let mut t: Vec<u8> = Vec::new();
+    for (i, chunk) in out.chunks_mut(32).enumerate() {
+        let counter = [(i + 1) as u8];
+        let mut parts: Vec<&[u8]> = Vec::with_capacity(info.len() + 2);
+        parts.push(&t);
+        parts.extend_from_slice(info);
+        parts.push(&counter);
+        let block = hmac_sha256(prk, &parts);
+        chunk.copy_from_slice(&block[..chunk.len()]);
+        t = block.to_vec();
+    }
+    wipe(&mut t);
+}
+
+/// Reduce a big-endian integer (e.g. 48 bytes of HKDF output) mod r, as 32 big-endian bytes.
+fn reduce_mod_r(okm: &[u8]) -> [u8; 32] {
+    let order = dashu::integer::UBig::from_be_bytes(&SCALAR_ORDER);
+    let sk = dashu::integer::UBig::from_be_bytes(okm) % &order;
+    let be = sk.to_be_bytes();
+    let mut out = [0u8; 32];
+    out[32 - be.len()..].copy_from_slice(&be);
+    out
+}
+
+/// Overwrite a buffer with zeros in a way the compiler won't optimize out.
+fn wipe(buf: &mut [u8]) {
+    for b in buf.iter_mut() {
+        // SAFETY: b is a valid, aligned &mut u8
+        unsafe { core::ptr::write_volatile(b, 0) };
+    }
+    core::sync::atomic::compiler_fence(core::sync::atomic::Ordering::SeqCst);
 }
 
 #[cfg(test)]
@@ -69,8 +491,415 @@ mod tests {
         let sk = PrivKey::from_random();
         let msg = b"compress-rt";
         let sig = sk.sign(msg).expect("sign");
-        let sig_c = G1CompressedPoint::try_from(sig.clone()).expect("compress");
+        let sig_c = G1CompressedPoint::try_from(sig).expect("compress");
         let sig_rt = G1Point::try_from(&sig_c).expect("decompress");
         assert_eq!(sig.0, sig_rt.0, "sig compress/decompress mismatch");
     }
+
+    #[test]
+    fn context_signatures_do_not_cross_verify() {
+        let sk = PrivKey::from_random();
+        let pk = G2Point::try_from(&sk).expect("g2 from sk");
+        let msg = b"ctx-msg";
+
+        let sig_a = sk.sign_context(b"subsystem-a", msg).expect("sign a");
+        pk.verify_context(&sig_a, b"subsystem-a", msg).expect("verify a");
+
+        let err = pk.verify_context(&sig_a, b"subsystem-b", msg).unwrap_err();
+        assert_eq!(err, crate::errors::BLSError::BLSVerificationError);
+        assert!(pk.verify(&sig_a, msg).is_err());
+
+        // Context signatures hash under their own tag, so plain-signing the framed bytes
+        // doesn't produce one
+        let mut framed = (11u32).to_be_bytes().to_vec();
+        framed.extend_from_slice(b"subsystem-a");
+        framed.extend_from_slice(msg);
+        let plain = sk.sign(&framed).expect("sign framed");
+        assert!(pk.verify_context(&plain, b"subsystem-a", msg).is_err());
+        assert!(pk.verify(&sig_a, &framed).is_err());
+    }
+
+    #[test]
+    fn pubkey_g2_is_the_verifying_key() {
+        let sk = PrivKey::from_random();
+        let msg = b"pubkey-g2";
+        let sig = sk.sign(msg).expect("sign");
+        let pk = sk.pubkey_g2().expect("pubkey_g2");
+        pk.verify(&sig, msg).expect("verify");
+
+        // The G1 derivation carries the same scalar: e(sk * G1, -G2) * e(G1, sk * G2) == 1
+        use crate::bn254::pairing;
+        use crate::consts::G2_MINUS_ONE;
+        let pk_g1 = G1Point::try_from(PrivKey(sk.0)).expect("g1 from sk");
+        let pairs = [(pk_g1, G2Point(G2_MINUS_ONE)), (G1Point::generator(), pk)];
+        assert!(pairing(&pairs).expect("pairing"));
+        let other = PrivKey::from_random().pubkey_g2().expect("other");
+        let pairs = [(pk_g1, G2Point(G2_MINUS_ONE)), (G1Point::generator(), other)];
+        assert!(!pairing(&pairs).expect("pairing"));
+    }
+
+    #[test]
+    fn self_verify_detects_corrupted_key() {
+        let sk = PrivKey::from_random();
+        let msg = b"self-verify";
+        sk.self_verify(msg).expect("self verify");
+
+        // A key with a flipped bit signs, but not for the original pubkey
+        let mut corrupted = PrivKey(sk.0);
+        corrupted.0[31] ^= 1;
+        let sig = corrupted.sign(msg).expect("sign");
+        let pk = sk.pubkey_g2().expect("pubkey_g2");
+        let err = pk.verify(&sig, msg).unwrap_err();
+        assert_eq!(err, crate::errors::BLSError::BLSVerificationError);
+    }
+
+    #[test]
+    fn hex_roundtrip_and_range_check() {
+        let sk = PrivKey::from_random();
+        let hex = sk.to_hex();
+        assert_eq!(hex.len(), 64);
+        assert_eq!(PrivKey::from_hex(&hex).expect("from hex").0, sk.0);
+        assert_eq!(PrivKey::from_hex(&format!("0x{}", hex.to_uppercase())).unwrap().0, sk.0);
+
+        // r itself and anything above it are out of range, as is zero
+        let r = "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+        let r_minus_one = "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000000";
+        assert!(PrivKey::from_hex(r_minus_one).is_ok());
+        assert!(matches!(PrivKey::from_hex(r), Err(crate::errors::BLSError::ScalarOutOfRange)));
+        let above = PrivKey::from_hex(&"ff".repeat(32));
+        assert!(matches!(above, Err(crate::errors::BLSError::ScalarOutOfRange)));
+        let zero = PrivKey::from_hex(&"00".repeat(32));
+        assert!(matches!(zero, Err(crate::errors::BLSError::ScalarOutOfRange)));
+
+        // Wrong length or a non-hex digit
+        let short = PrivKey::from_hex(&hex[..62]);
+        assert!(matches!(short, Err(crate::errors::BLSError::SecretKeyError)));
+        let bad_digit = PrivKey::from_hex(&format!("{}zz", &hex[..62]));
+        assert!(matches!(bad_digit, Err(crate::errors::BLSError::SecretKeyError)));
+    }
+
+    #[test]
+    fn aad_must_match_what_was_signed() {
+        let sk = PrivKey::from_random();
+        let pk = sk.pubkey_g2().expect("pubkey_g2");
+        let msg = b"aad-msg";
+
+        let sig = sk.sign_with_aad(msg, b"header-v1").expect("sign");
+        pk.verify_with_aad(&sig, msg, b"header-v1").expect("verify");
+
+        let err = pk.verify_with_aad(&sig, msg, b"header-v2").unwrap_err();
+        assert_eq!(err, crate::errors::BLSError::BLSVerificationError);
+        assert!(pk.verify_with_aad(&sig, msg, b"").is_err());
+        assert!(pk.verify(&sig, msg).is_err());
+
+        // AAD and context signatures don't cross-verify, even with the AAD tag as the context
+        // and the AAD framing as the message
+        let mut framed = (9u32).to_be_bytes().to_vec();
+        framed.extend_from_slice(b"header-v1");
+        framed.extend_from_slice(msg);
+        let ctx = sk.sign_context(crate::hash::AAD_DOMAIN, &framed).expect("sign context");
+        assert!(pk.verify_with_aad(&ctx, msg, b"header-v1").is_err());
+        assert!(pk.verify_context(&sig, crate::hash::AAD_DOMAIN, &framed).is_err());
+    }
+
+    // Only meaningful with the feature on. Every signing call in the suite acknowledges first
+    // (see test_utils::acknowledge_security), so the refusal is checked through security_gate.
+    #[cfg(feature = "strict-security")]
+    #[test]
+    fn strict_security_requires_acknowledgment() {
+        use super::{acknowledge_security_level, security_gate};
+        use crate::errors::BLSError;
+
+        assert_eq!(security_gate(false).unwrap_err(), BLSError::BLSSigningError);
+        assert!(security_gate(true).is_ok());
+        assert_eq!(acknowledge_security_level(128).unwrap_err(), BLSError::BLSSigningError);
+
+        acknowledge_security_level(crate::consts::SECURITY_BITS).expect("acknowledge");
+        PrivKey::from_random().self_verify(b"strict").expect("sign after acknowledgment");
+    }
+
+    #[test]
+    fn valid_from_rejected_until_epoch() {
+        let sk = PrivKey::from_random();
+        let pk = sk.pubkey_g2().expect("pubkey_g2");
+        let msg = b"time-locked";
+        let sig = sk.sign_valid_from(10, msg).expect("sign");
+
+        let err = pk.verify_valid_from(&sig, 9, 10, msg).unwrap_err();
+        assert_eq!(err, crate::errors::BLSError::BLSVerificationError);
+        pk.verify_valid_from(&sig, 10, 10, msg).expect("valid at epoch");
+        pk.verify_valid_from(&sig, 11, 10, msg).expect("valid after epoch");
+
+        // Claiming an earlier epoch than was signed doesn't help
+        assert!(pk.verify_valid_from(&sig, 9, 9, msg).is_err());
+    }
+
+    #[test]
+    fn possession_proofs_bind_the_key() {
+        use crate::g2::verify_possession;
+        let a = PrivKey::from_random();
+        let b = PrivKey::from_random();
+        let pk_a = a.pubkey_g2().expect("pk a");
+        let pk_b = b.pubkey_g2().expect("pk b");
+
+        let pop_a = a.prove_possession().expect("pop");
+        verify_possession(&pk_a, &pop_a).expect("valid pop");
+        assert!(verify_possession(&pk_b, &pop_a).is_err());
+
+        // A PoP isn't a message signature over the key bytes, and such a signature isn't a PoP
+        assert!(pk_a.verify(&pop_a, pk_a.0).is_err());
+        let sig = a.sign(pk_a.0).expect("sign");
+        assert!(verify_possession(&pk_a, &sig).is_err());
+    }
+
+    #[test]
+    fn debug_redacts_private_key() {
+        let sk = PrivKey::from_random();
+        let hex = sk.to_hex();
+        let debug = format!("{:?}", sk);
+        assert_eq!(debug, "PrivKey(<redacted>)");
+        assert!(!debug.contains(&hex[..8]));
+        assert_eq!(format!("{:?}", Some(PrivKey(sk.0))), "Some(PrivKey(<redacted>))");
+    }
+
+    #[test]
+    fn try_from_slice_checks_length_and_range() {
+        use crate::consts::SCALAR_ORDER;
+        use crate::errors::BLSError;
+
+        let sk = PrivKey::from_hex(&PrivKey::from_random().to_hex()).expect("in range");
+        assert_eq!(PrivKey::try_from(&sk.0[..]).expect("exact").0, sk.0);
+
+        // Length: empty, one short, one over
+        let long = [1u8; 33];
+        for len in [0, 31, 33] {
+            let err = PrivKey::try_from(&long[..len]).unwrap_err();
+            assert_eq!(err, BLSError::InvalidLength { expected: 32, got: len });
+        }
+
+        // Range: 0 and r fail, r - 1 succeeds
+        let mut r_minus_one = SCALAR_ORDER;
+        r_minus_one[31] -= 1;
+        assert!(matches!(PrivKey::try_from(&[0u8; 32][..]), Err(BLSError::ScalarOutOfRange)));
+        assert!(matches!(PrivKey::try_from(&SCALAR_ORDER[..]), Err(BLSError::ScalarOutOfRange)));
+        assert_eq!(PrivKey::try_from(&r_minus_one[..]).expect("r - 1").0, r_minus_one);
+    }
+
+    #[test]
+    fn privkey_ct_eq_and_zeroized_on_drop() {
+        let sk = PrivKey::from_random();
+        let same = PrivKey(*sk.expose_secret());
+        let mut other_bytes = sk.0;
+        other_bytes[31] ^= 1;
+        assert!(sk.ct_eq(&same));
+        assert!(!sk.ct_eq(&PrivKey(other_bytes)));
+
+        // The wipe helper itself
+        let mut buf = [0x5au8; 32];
+        super::wipe(&mut buf);
+        assert_eq!(buf, [0u8; 32]);
+
+        // Run the destructor in a MaybeUninit slot and read the bytes it left behind through a
+        // raw pointer, without touching the dropped PrivKey as a value
+        let mut slot = core::mem::MaybeUninit::new(PrivKey([0x5a; 32]));
+        // SAFETY: the slot holds an initialized PrivKey until assume_init_drop, which runs its
+        // destructor once. Afterwards only the raw bytes of the field are read
+        let left = unsafe {
+            let bytes = core::ptr::addr_of!((*slot.as_ptr()).0);
+            slot.assume_init_drop();
+            core::ptr::read_volatile(bytes)
+        };
+        assert_eq!(left, [0u8; 32]);
+    }
+
+    #[test]
+    fn privkey_from_bytes_validates_range() {
+        use crate::consts::{MODULUS, SCALAR_ORDER};
+        use crate::errors::BLSError;
+
+        let sk = PrivKey::from_random();
+        assert_eq!(PrivKey::from_bytes(sk.0).expect("valid").0, sk.0);
+
+        let mut modulus = [0u8; 32];
+        let be = MODULUS.to_be_bytes();
+        modulus[32 - be.len()..].copy_from_slice(&be);
+        assert!(matches!(PrivKey::from_bytes(modulus), Err(BLSError::ScalarOutOfRange)));
+        assert!(matches!(PrivKey::from_bytes(SCALAR_ORDER), Err(BLSError::ScalarOutOfRange)));
+        assert!(matches!(PrivKey::from_bytes([0u8; 32]), Err(BLSError::ScalarOutOfRange)));
+    }
+
+    #[test]
+    fn hkdf_matches_rfc5869_case_1() {
+        let ikm = [0x0bu8; 22];
+        let salt: Vec<u8> = (0x00..=0x0c).collect();
+        let info: Vec<u8> = (0xf0..=0xf9).collect();
+
+        let prk = super::hmac_sha256(&salt, &[&ikm]);
+        assert_eq!(
+            prk.iter().map(|b| format!("{:02x}", b)).collect::<String>(),
+            "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5"
+        );
+        let mut okm = [0u8; 42];
+        super::hkdf_expand(&prk, &[&info], &mut okm);
+        assert_eq!(
+            okm.iter().map(|b| format!("{:02x}", b)).collect::<String>(),
+            "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"
+        );
+    }
+
+    #[test]
+    fn derive_from_seed_is_deterministic() {
+        let seed = [0x42u8; 32];
+        let a = PrivKey::derive_from_seed(&seed, 0).expect("derive");
+        let b = PrivKey::derive_from_seed(&seed, 0).expect("derive");
+        let c = PrivKey::derive_from_seed(&seed, 1).expect("derive");
+        assert!(a.ct_eq(&b));
+        assert!(!a.ct_eq(&c));
+        assert!(PrivKey::from_bytes(a.0).is_ok() && PrivKey::from_bytes(c.0).is_ok());
+        assert_eq!(
+            a.to_hex(),
+            "1fc564f5da6364bb60e69396cee03c692eb2066681fddcfccb87afcb24ad1769"
+        );
+
+        let err = PrivKey::derive_from_seed(&seed[..31], 0).unwrap_err();
+        assert_eq!(err, crate::errors::BLSError::InvalidLength { expected: 32, got: 31 });
+    }
+
+    #[test]
+    fn from_rng_is_reproducible() {
+        use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+        let a = PrivKey::from_rng(&mut StdRng::seed_from_u64(7));
+        let b = PrivKey::from_rng(&mut StdRng::seed_from_u64(7));
+        let c = PrivKey::from_rng(&mut StdRng::seed_from_u64(8));
+        assert!(a.ct_eq(&b));
+        assert!(!a.ct_eq(&c));
+
+        // Works through a trait object too
+        let mut rng = StdRng::seed_from_u64(7);
+        let dyn_rng: &mut dyn RngCore = &mut rng;
+        assert!(PrivKey::from_rng(dyn_rng).ct_eq(&a));
+    }
+
+    #[test]
+    fn sign_rejects_out_of_range_keys() {
+        use crate::consts::SCALAR_ORDER;
+        use crate::errors::BLSError;
+
+        let mut r_minus_one = SCALAR_ORDER;
+        r_minus_one[31] -= 1;
+        assert_eq!(PrivKey([0u8; 32]).sign(b"m").unwrap_err(), BLSError::ScalarOutOfRange);
+        assert_eq!(PrivKey(SCALAR_ORDER).sign(b"m").unwrap_err(), BLSError::ScalarOutOfRange);
+        assert!(PrivKey(r_minus_one).sign(b"m").is_ok());
+    }
+
+    #[test]
+    fn ct_eq_visits_every_byte() {
+        use super::ct_eq_bytes;
+        use crate::g2::G2CompressedPoint;
+
+        // A difference in the first byte and one in the last are both caught; with no early
+        // exit, which byte differs has no effect on the work done
+        let a = [7u8; 64];
+        let mut first = a;
+        first[0] ^= 1;
+        let mut last = a;
+        last[63] ^= 1;
+        assert!(ct_eq_bytes(&a, &a));
+        assert!(!ct_eq_bytes(&a, &first) && !ct_eq_bytes(&a, &last));
+        assert!(!ct_eq_bytes(&a, &a[..63]));
+
+        let sk = PrivKey::from_random();
+        let g1 = G1CompressedPoint::try_from(PrivKey(sk.0)).expect("g1");
+        let g2 = G2CompressedPoint::try_from(&G2Point::try_from(&sk).expect("g2")).expect("g2c");
+        assert!(g1.ct_eq(&g1) && !g1.ct_eq(&G1CompressedPoint([0u8; 32])));
+        assert!(g2.ct_eq(&g2) && !g2.ct_eq(&G2CompressedPoint([0u8; 64])));
+    }
+
+    #[cfg(feature = "subtle")]
+    #[test]
+    fn subtle_ct_eq_matches_inherent() {
+        use crate::g2::G2CompressedPoint;
+        use subtle::ConstantTimeEq;
+
+        let sk = PrivKey::from_random();
+        let other = PrivKey::from_random();
+        assert!(bool::from(ConstantTimeEq::ct_eq(&sk, &PrivKey(sk.0))));
+        assert!(!bool::from(ConstantTimeEq::ct_eq(&sk, &other)));
+
+        let g1 = G1CompressedPoint::try_from(PrivKey(sk.0)).expect("g1");
+        let g1_other = G1CompressedPoint::try_from(PrivKey(other.0)).expect("g1");
+        assert!(bool::from(ConstantTimeEq::ct_eq(&g1, &g1)));
+        assert!(!bool::from(ConstantTimeEq::ct_eq(&g1, &g1_other)));
+
+        let g2 = G2CompressedPoint::try_from(&G2Point::try_from(&sk).expect("g2")).expect("g2c");
+        assert!(bool::from(ConstantTimeEq::ct_eq(&g2, &g2)));
+        assert!(!bool::from(ConstantTimeEq::ct_eq(&g2, &G2CompressedPoint([0u8; 64]))));
+    }
+
+    // Rough timing check, not a proof: run with `cargo test --release -- --ignored` on a quiet
+    // machine. An early exit would make a first-byte difference much cheaper than a last-byte one
+    #[test]
+    #[ignore]
+    fn ct_eq_timing_does_not_depend_on_the_differing_byte() {
+        use super::ct_eq_bytes;
+        use std::time::Instant;
+
+        let a = [7u8; 64];
+        let mut first = a;
+        first[0] ^= 1;
+        let mut last = a;
+        last[63] ^= 1;
+        let time = |b: &[u8; 64]| {
+            let start = Instant::now();
+            for _ in 0..1_000_000 {
+                core::hint::black_box(ct_eq_bytes(core::hint::black_box(&a), b));
+            }
+            start.elapsed().as_secs_f64()
+        };
+        let (t_first, t_last) = (time(&first), time(&last));
+        let ratio = t_first / t_last;
+        assert!((0.8..1.25).contains(&ratio), "first {t_first}s vs last {t_last}s");
+    }
+
+    #[test]
+    fn from_ikm_is_deterministic_and_reduced() {
+        use crate::consts::SCALAR_ORDER;
+        use crate::errors::BLSError;
+
+        let ikm: Vec<u8> = (0u8..32).collect();
+        let sk = PrivKey::from_ikm(&ikm, b"brine-salt", b"feed-1").expect("from_ikm");
+        // Cross-checked against Python's hmac module: plain HKDF-SHA256, 48 bytes, mod r
+        assert_eq!(
+            sk.to_hex(),
+            "25b21f5d7eb352e68afdbaf70de376601672fc6d62c76f85a1b54ed388ef3f82"
+        );
+        assert!(sk.ct_eq(&PrivKey::from_ikm(&ikm, b"brine-salt", b"feed-1").expect("again")));
+        assert!(!sk.ct_eq(&PrivKey::from_ikm(&ikm, b"brine-salt", b"feed-2").expect("other")));
+
+        for i in 0u32..64 {
+            let k = PrivKey::from_ikm(&ikm, b"", &i.to_be_bytes()).expect("k");
+            assert!(k.0 < SCALAR_ORDER && k.0 != [0u8; 32]);
+        }
+        let err = PrivKey::from_ikm(&ikm[..31], b"", b"").unwrap_err();
+        assert_eq!(err, BLSError::InvalidLength { expected: 32, got: 31 });
+    }
+
+    #[test]
+    fn derive_child_distinct_and_usable() {
+        let master = PrivKey::from_random();
+        let mut seen = std::collections::HashSet::new();
+        for i in 0u32..1000 {
+            assert!(seen.insert(master.derive_child(i).expect("child").as_bytes()));
+        }
+        assert!(!seen.contains(&master.as_bytes()));
+        assert!(master.derive_child(7).unwrap().ct_eq(&master.derive_child(7).unwrap()));
+
+        let child = master.derive_child(42).expect("child");
+        let pk = child.pubkey_g2().expect("child pubkey");
+        assert!(pk.is_on_curve() && pk.is_in_subgroup());
+        let sig = child.sign(b"child-message").expect("sign");
+        pk.verify(&sig, b"child-message").expect("verify");
+        assert!(master.pubkey_g2().unwrap().verify(&sig, b"child-message").is_err());
+    }
 }
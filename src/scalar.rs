@@ -0,0 +1,169 @@
+// Scalars in Fr, the field of integers mod the group order r.
+//
+// Secret keys, threshold coefficients and blinding factors are all elements of Fr. Wrapping them
+// in a type that can only be built from a range-checked value keeps unreduced bytes from reaching
+// the multiplication syscall, where r + k and k would silently give the same point.
+//
+// The bytes are big-endian, matching the syscall's scalar layout. Arithmetic needs arkworks and is
+// only available off-chain; on-chain the type just carries values validated elsewhere.
+
+#[cfg(not(target_os = "solana"))]
+use ark_ff::{BigInteger, PrimeField};
+#[cfg(not(target_os = "solana"))]
+use core::ops::{Add, Mul, Neg, Sub};
+#[cfg(not(target_os = "solana"))]
+use rand::RngCore;
+
+use crate::consts::SCALAR_ORDER;
+use crate::errors::BLSError;
+use crate::privkey::PrivKey;
+
+/// A scalar in 0..r, as 32 big-endian bytes.
+/// No Debug impl, since the value is often a secret.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Fr(pub [u8; 32]);
+
+impl Fr {
+    /// Wrap 32 big-endian bytes, or Err(ScalarOutOfRange) if they encode a value >= r.
+    pub fn from_be_bytes_checked(bytes: [u8; 32]) -> Result<Fr, BLSError> {
+        // Fixed-width big-endian arrays compare like the integers they encode
+        if bytes >= SCALAR_ORDER {
+            return Err(BLSError::ScalarOutOfRange);
+        }
+        Ok(Fr(bytes))
+    }
+
+    /// A uniformly random scalar, by rejection sampling.
+    #[cfg(not(target_os = "solana"))]
+    pub fn random() -> Fr {
+        loop {
+            let mut bytes = [0u8; 32];
+            rand::thread_rng().fill_bytes(&mut bytes);
+            if let Ok(s) = Fr::from_be_bytes_checked(bytes) {
+                return s;
+            }
+        }
+    }
+
+    pub const fn zero() -> Fr {
+        Fr([0u8; 32])
+    }
+
+    pub const fn one() -> Fr {
+        let mut bytes = [0u8; 32];
+        bytes[31] = 1;
+        Fr(bytes)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == [0u8; 32]
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    pub(crate) fn to_ark(self) -> ark_bn254::Fr {
+        ark_bn254::Fr::from_be_bytes_mod_order(&self.0)
+    }
+
+    #[cfg(not(target_os = "solana"))]
+    pub(crate) fn from_ark(x: &ark_bn254::Fr) -> Fr {
+        let be = x.into_bigint().to_bytes_be();
+        let mut out = [0u8; 32];
+        out[32 - be.len()..].copy_from_slice(&be);
+        Fr(out)
+    }
+}
+
+impl From<Fr> for [u8; 32] {
+    fn from(value: Fr) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<[u8; 32]> for Fr {
+    type Error = BLSError;
+
+    fn try_from(value: [u8; 32]) -> Result<Self, Self::Error> {
+        Fr::from_be_bytes_checked(value)
+    }
+}
+
+/// Use a private key as a signing scalar; Err(ScalarOutOfRange) if it isn't below r.
+impl TryFrom<&PrivKey> for Fr {
+    type Error = BLSError;
+
+    fn try_from(value: &PrivKey) -> Result<Self, Self::Error> {
+        Fr::from_be_bytes_checked(value.0)
+    }
+}
+
+#[cfg(not(target_os = "solana"))]
+impl Add for Fr {
+    type Output = Fr;
+
+    fn add(self, rhs: Fr) -> Fr {
+        Fr::from_ark(&(self.to_ark() + rhs.to_ark()))
+    }
+}
+
+#[cfg(not(target_os = "solana"))]
+impl Sub for Fr {
+    type Output = Fr;
+
+    fn sub(self, rhs: Fr) -> Fr {
+        Fr::from_ark(&(self.to_ark() - rhs.to_ark()))
+    }
+}
+
+#[cfg(not(target_os = "solana"))]
+impl Mul for Fr {
+    type Output = Fr;
+
+    fn mul(self, rhs: Fr) -> Fr {
+        Fr::from_ark(&(self.to_ark() * rhs.to_ark()))
+    }
+}
+
+#[cfg(not(target_os = "solana"))]
+impl Neg for Fr {
+    type Output = Fr;
+
+    fn neg(self) -> Fr {
+        Fr::from_ark(&-self.to_ark())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Fr;
+    use crate::consts::SCALAR_ORDER;
+    use crate::errors::BLSError;
+
+    #[test]
+    fn fr_range_check() {
+        let mut r_minus_one = SCALAR_ORDER;
+        r_minus_one[31] -= 1;
+        assert!(Fr::from_be_bytes_checked(r_minus_one).is_ok());
+        assert!(matches!(Fr::try_from(SCALAR_ORDER), Err(BLSError::ScalarOutOfRange)));
+        assert!(matches!(Fr::try_from([0xff; 32]), Err(BLSError::ScalarOutOfRange)));
+        assert!(Fr::zero().is_zero() && !Fr::one().is_zero());
+        assert_eq!(<[u8; 32]>::from(Fr::one())[31], 1);
+    }
+
+    #[test]
+    fn fr_arithmetic_wraps_mod_r() {
+        let mut r_minus_one = SCALAR_ORDER;
+        r_minus_one[31] -= 1;
+        let minus_one = Fr(r_minus_one);
+
+        assert!(-Fr::one() == minus_one);
+        assert!(minus_one + Fr::one() == Fr::zero());
+        assert!(Fr::zero() - Fr::one() == minus_one);
+        assert!(minus_one * minus_one == Fr::one());
+
+        let a = Fr::random();
+        let b = Fr::random();
+        assert!((a + b) - b == a);
+        assert!(a * b == b * a);
+        assert!(a * Fr::one() == a);
+    }
+}
@@ -0,0 +1,36 @@
+// Fixtures shared by the unit tests of several modules.
+
+use crate::g2::G2CompressedPoint;
+
+/// Acknowledge the security level once per test process, so strict-security builds can sign.
+/// Notes:
+/// - The flag is process-wide and the harness runs tests in parallel and in no fixed order, so
+///   a test that acknowledged itself would unlock signing for some neighbours and not others.
+///   check_security_acknowledged calls this in test builds instead.
+#[cfg(feature = "strict-security")]
+pub(crate) fn acknowledge_security() {
+    static ONCE: std::sync::Once = std::sync::Once::new();
+    ONCE.call_once(|| {
+        crate::privkey::acknowledge_security_level(crate::consts::SECURITY_BITS)
+            .expect("SECURITY_BITS is always acceptable");
+    });
+}
+
+/// A G2 curve point that is not in the prime-order subgroup, as a compressed encoding.
+pub(crate) fn off_subgroup_compressed() -> G2CompressedPoint {
+    use ark_bn254::{Fq2, G2Affine};
+    use ark_serialize::CanonicalSerialize;
+
+    let mut x = Fq2::from(1u64);
+    loop {
+        if let Some(p) = G2Affine::get_point_from_x_unchecked(x, true) {
+            if !p.is_in_correct_subgroup_assuming_on_curve() {
+                let mut bytes = [0u8; 64];
+                p.serialize_compressed(&mut bytes[..]).unwrap();
+                bytes.reverse();
+                return G2CompressedPoint(bytes);
+            }
+        }
+        x += Fq2::from(1u64);
+    }
+}
@@ -0,0 +1,1782 @@
+// BLS threshold verification against an on-chain committee.
+//
+// The program stores the committee's G2 public keys (each registered with a PoP) and exposes them
+// through a PubkeyProvider. An aggregator submits the aggregated G1 signature together with the
+// indices of the committee members that signed. The "A1" check then verifies, in a single pairing
+// call, that the signature is exactly the sum of those members' partial signatures:
+//
+//   e(H(m), PK_i1) * ... * e(H(m), PK_ik) * e(S_sum, -G2) == 1
+//
+// If it passes, the indices are both a threshold proof (k signed) and an attribution (these k).
+// Partial signatures are produced with utils::bls_partial_sign and summed with
+// utils::aggregate_partials.
+
+#[cfg(not(target_os = "solana"))]
+use num::CheckedAdd;
+
+use crate::bn254::{g1_add, g1_mul, g2_neg, pairing};
+use crate::consts::G2_MINUS_ONE;
+use crate::errors::BLSError;
+use crate::g1::{G1CompressedPoint, G1Point};
+use crate::g2::{verify_possession, G2CompressedPoint, G2Point};
+use crate::hash::{hash_to_curve, hash_to_scalar};
+use crate::scalar::Fr;
+use crate::utils::{self, aggregate_partials, verify_all_except, verify_augmented};
+
+/// Source of committee public keys by index.
+pub trait PubkeyProvider {
+    /// Return the G2 public key of committee member `index`.
+    fn g2_by_index(&self, index: u16) -> Result<G2Point, BLSError>;
+}
+
+/// Committee registry stored as a blob of concatenated compressed G2 public keys (64 bytes each),
+/// e.g. straight from account data. Keys are decompressed on lookup.
+pub struct Registry<'a> {
+    blob: &'a [u8],
+}
+
+impl<'a> Registry<'a> {
+    /// Wrap a blob. Its length must be a non-zero multiple of 64 with at most 65536 keys.
+    pub fn new(blob: &'a [u8]) -> Result<Self, BLSError> {
+        let keys = blob.len() / 64;
+        if blob.is_empty() || !blob.len().is_multiple_of(64) || keys > u16::MAX as usize + 1 {
+            return Err(BLSError::SerializationError);
+        }
+        Ok(Self { blob })
+    }
+
+    /// Number of keys in the registry.
+    pub fn len(&self) -> usize {
+        self.blob.len() / 64
+    }
+
+    /// Always false, since new rejects empty blobs.
+    pub fn is_empty(&self) -> bool {
+        self.blob.is_empty()
+    }
+
+    /// The compressed key stored at `index`.
+    pub fn compressed(&self, index: u16) -> Result<G2CompressedPoint, BLSError> {
+        let off = index as usize * 64;
+        let bytes = self
+            .blob
+            .get(off..off + 64)
+            .ok_or(BLSError::IndexOutOfBounds)?;
+        Ok(G2CompressedPoint(bytes.try_into().map_err(|_| BLSError::SerializationError)?))
+    }
+
+    /// Index of the first key that fails to decompress, is the identity or is outside the G2
+    /// subgroup.
+    pub fn first_invalid(&self) -> Option<u16> {
+        (0..self.len()).map(|i| i as u16).find(|&i| {
+            !self
+                .g2_by_index(i)
+                .map(|pk| !pk.is_identity() && pk.is_in_subgroup())
+                .unwrap_or(false)
+        })
+    }
+
+    /// One-pass well-formedness check, meant to run once at registration time.
+    /// Output:
+    /// - Ok if every key decompresses, is not the identity and is in the G2 subgroup
+    /// - Err((Some(index), e)) for the first key that doesn't: the decompression error,
+    ///   PointAtInfinity for the identity or InvalidPublicKey outside the subgroup
+    /// - Err((None, e)) if the pairing call failed but no single key explains it
+    ///
+    /// Notes:
+    /// - The fast path is a single pairing call over all keys; per-key checks only run to find
+    ///   the failing index.
+    pub fn validate(&self) -> Result<(), (Option<u16>, BLSError)> {
+        let pairs = (0..self.len())
+            .map(|i| {
+                let pk = self.g2_by_index(i as u16).map_err(|e| (Some(i as u16), e))?;
+                if pk.is_identity() {
+                    return Err((Some(i as u16), BLSError::PointAtInfinity));
+                }
+                Ok((G1Point::IDENTITY, pk))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // e(0, PK_i) is always one, so this only fails if some PK_i is rejected by the syscall
+        pairing(&pairs).map(|_| ()).map_err(|e| match self.first_invalid() {
+            Some(i) => (Some(i), BLSError::InvalidPublicKey),
+            None => (None, e),
+        })
+    }
+}
+
+impl PubkeyProvider for Registry<'_> {
+    fn g2_by_index(&self, index: u16) -> Result<G2Point, BLSError> {
+        G2Point::try_from(self.compressed(index)?)
+    }
+}
+
+/// Committee keys held in a slice, e.g. a Vec built off-chain or in a test.
+pub struct SlicePubkeyProvider<'a>(pub &'a [G2Point]);
+
+impl PubkeyProvider for SlicePubkeyProvider<'_> {
+    fn g2_by_index(&self, index: u16) -> Result<G2Point, BLSError> {
+        self.0
+            .get(index as usize)
+            .copied()
+            .ok_or(BLSError::IndexOutOfBounds)
+    }
+}
+
+/// Committee keys held inline, for small committees that fit on the stack.
+pub struct ArrayPubkeyProvider<const N: usize>(pub [G2Point; N]);
+
+impl<const N: usize> PubkeyProvider for ArrayPubkeyProvider<N> {
+    fn g2_by_index(&self, index: u16) -> Result<G2Point, BLSError> {
+        self.0
+            .get(index as usize)
+            .copied()
+            .ok_or(BLSError::IndexOutOfBounds)
+    }
+}
+
+/// Compressed committee keys in a slice, decompressed on lookup like Registry.
+pub struct CompressedSlicePubkeyProvider<'a>(pub &'a [G2CompressedPoint]);
+
+impl PubkeyProvider for CompressedSlicePubkeyProvider<'_> {
+    fn g2_by_index(&self, index: u16) -> Result<G2Point, BLSError> {
+        let compressed = self.0.get(index as usize).ok_or(BLSError::IndexOutOfBounds)?;
+        G2Point::try_from(*compressed)
+    }
+}
+
+/// Source of committee weights (e.g. stake) by index, read from the same trusted state as the
+/// public keys.
+pub trait WeightProvider {
+    /// Return the weight of committee member `index`.
+    fn weight_by_index(&self, index: u16) -> Result<u64, BLSError>;
+}
+
+/// Committee weights held in a slice, indexed like SlicePubkeyProvider.
+pub struct SliceWeightProvider<'a>(pub &'a [u64]);
+
+impl WeightProvider for SliceWeightProvider<'_> {
+    fn weight_by_index(&self, index: u16) -> Result<u64, BLSError> {
+        self.0
+            .get(index as usize)
+            .copied()
+            .ok_or(BLSError::IndexOutOfBounds)
+    }
+}
+
+/// Helper to check that a list of signer indices has no duplicates.
+/// Pairwise for short lists; past 64 entries a sorted copy is cheaper than n^2 compares.
+pub(crate) fn check_no_duplicate_indices(indices: &[u16]) -> bool {
+    if indices.len() > 64 {
+        let mut sorted = indices.to_vec();
+        sorted.sort_unstable();
+        return sorted.windows(2).all(|w| w[0] != w[1]);
+    }
+    for i in 0..indices.len() {
+        for j in (i + 1)..indices.len() {
+            if indices[i] == indices[j] {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// A1 threshold verify over an explicit list of signer indices.
+/// Input:
+/// - message: message bytes
+/// - signer_indices: committee indices of the members that signed
+/// - s_sum: aggregated G1 signature = sum of the signers' partial signatures
+/// - pk_provider: committee public keys
+///
+/// Output:
+/// - Ok if the aggregate verifies for exactly these signers, Err otherwise
+///
+/// Important:
+/// - Like verify_fast_aggregate, this is only safe if every committee key has a PoP.
+/// - Duplicate indices are rejected with DuplicateSigner so a signer can't be counted more than
+///   once, e.g. [0, 0, 0] passing for three signers with only member 0's partial.
+/// - An identity s_sum or signer key is rejected with PointAtInfinity.
+pub fn verify_a1_with_indices<M: AsRef<[u8]>>(
+    message: M,
+    signer_indices: &[u16],
+    s_sum: G1Point,
+    pk_provider: &impl PubkeyProvider,
+) -> Result<(), BLSError> {
+    if signer_indices.is_empty() {
+        return Err(BLSError::EmptySignerSet);
+    }
+    if !check_no_duplicate_indices(signer_indices) {
+        return Err(BLSError::DuplicateSigner);
+    }
+    if s_sum.is_identity() {
+        return Err(BLSError::PointAtInfinity);
+    }
+
+    // Hash message to G1 once
+    let h_g1 = hash_to_curve(message.as_ref())?;
+
+    // For each signer: pair (H(m), PK_i)
+    // Final pair: (S_sum, -G2)
+    let mut pairs = Vec::with_capacity(signer_indices.len() + 1);
+    for &i in signer_indices {
+        let pk = pk_provider.g2_by_index(i)?;
+        if pk.is_identity() {
+            return Err(BLSError::PointAtInfinity);
+        }
+        pairs.push((h_g1, pk));
+    }
+    pairs.push((s_sum, G2Point(G2_MINUS_ONE)));
+
+    if pairing(&pairs)? {
+        Ok(())
+    } else {
+        Err(BLSError::BLSVerificationError)
+    }
+}
+
+/// Verify several A1 aggregates from the same committee with one pairing call.
+/// Input:
+/// - messages: message m_j of each aggregate
+/// - sigs: aggregated signature S_j over m_j
+/// - signer_indices: committee indices that signed m_j
+/// - pk_provider: committee public keys
+///
+/// Output:
+/// - Ok if every aggregate verifies (with overwhelming probability), Err(BLSVerificationError)
+///   if any fails, without saying which
+///
+/// Notes:
+/// - Checks prod_i e(sum_{j : i signed m_j} r_j * H(m_j), PK_i) * e(sum_j r_j * S_j, -G2) == 1,
+///   so there is one pair per distinct signer rather than one per signature.
+/// - seed = sha256(for each j: len(m_j) as 4 bytes BE || m_j || S_j || len(indices_j) as 2
+///   bytes BE || indices_j as 2 bytes BE each), r_j = hash_to_scalar(seed || j as 4 bytes BE)
+///   under the "BLS-BN254-A1-BATCH" tag. The r_j depend on every signature, so they can't be
+///   known before the signatures are fixed.
+/// - Empty input is rejected with EmptyInput, and sigs or signer_indices of a different
+///   length than messages with InvalidLength. Each signer list and signature is checked like
+///   verify_a1_with_indices, so an identity signature or signer key is Err(PointAtInfinity).
+pub fn verify_a1_batch(
+    messages: &[&[u8]],
+    sigs: &[G1Point],
+    signer_indices: &[&[u16]],
+    pk_provider: &impl PubkeyProvider,
+) -> Result<(), BLSError> {
+    let n = messages.len();
+    if n == 0 {
+        return Err(BLSError::EmptyInput);
+    }
+    if let Some(got) = [sigs.len(), signer_indices.len()].into_iter().find(|&l| l != n) {
+        return Err(BLSError::InvalidLength { expected: n, got });
+    }
+
+    let mut transcript = Vec::new();
+    for ((message, sig), indices) in messages.iter().zip(sigs).zip(signer_indices) {
+        if indices.is_empty() {
+            return Err(BLSError::EmptySignerSet);
+        }
+        if !check_no_duplicate_indices(indices) {
+            return Err(BLSError::DuplicateSigner);
+        }
+        if sig.is_identity() {
+            return Err(BLSError::PointAtInfinity);
+        }
+        transcript.extend_from_slice(&(message.len() as u32).to_be_bytes());
+        transcript.extend_from_slice(message);
+        transcript.extend_from_slice(&sig.0);
+        transcript.extend_from_slice(&(indices.len() as u16).to_be_bytes());
+        for i in indices.iter() {
+            transcript.extend_from_slice(&i.to_be_bytes());
+        }
+    }
+    let seed = solana_nostd_sha256::hashv(&[&transcript]);
+
+    // Per-signer G1 sums, and the weighted signature sum
+    let mut per_signer: Vec<(u16, G1Point)> = Vec::new();
+    let mut sig_sum: Option<G1Point> = None;
+    let items = messages.iter().zip(sigs).zip(signer_indices);
+    for (j, ((message, sig), indices)) in items.enumerate() {
+        let r_input = [&seed[..], &(j as u32).to_be_bytes()].concat();
+        let r = hash_to_scalar(&r_input, b"BLS-BN254-A1-BATCH")?;
+        let h = g1_mul(hash_to_curve(message)?, &r.0)?;
+        for &i in indices.iter() {
+            match per_signer.iter_mut().find(|(k, _)| *k == i) {
+                Some((_, acc)) => *acc = g1_add(*acc, h)?,
+                None => per_signer.push((i, h)),
+            }
+        }
+        let weighted = g1_mul(*sig, &r.0)?;
+        sig_sum = Some(match sig_sum {
+            None => weighted,
+            Some(acc) => g1_add(acc, weighted)?,
+        });
+    }
+
+    let mut pairs = Vec::with_capacity(per_signer.len() + 1);
+    for (i, h) in per_signer {
+        let pk = pk_provider.g2_by_index(i)?;
+        if pk.is_identity() {
+            return Err(BLSError::PointAtInfinity);
+        }
+        pairs.push((h, pk));
+    }
+    pairs.push((sig_sum.unwrap(), G2Point(G2_MINUS_ONE)));
+
+    if pairing(&pairs)? {
+        Ok(())
+    } else {
+        Err(BLSError::BLSVerificationError)
+    }
+}
+
+/// A1 threshold verify that returns the attributed signer set.
+/// Output:
+/// - The signer indices, sorted ascending, if the aggregate verifies for exactly that set
+///
+/// Notes:
+/// - Same checks as verify_a1_with_indices, so the input order doesn't matter and duplicates
+///   are still rejected with DuplicateSigner rather than collapsed.
+pub fn verify_a1_and_attribute<M: AsRef<[u8]>>(
+    message: M,
+    signer_indices: &[u16],
+    s_sum: G1Point,
+    pk_provider: &impl PubkeyProvider,
+) -> Result<Vec<u16>, BLSError> {
+    verify_a1_with_indices(message, signer_indices, s_sum, pk_provider)?;
+    let mut signers = signer_indices.to_vec();
+    signers.sort_unstable();
+    Ok(signers)
+}
+
+/// Weighted A1 threshold verify, for stake-weighted committees.
+/// Input:
+/// - message: message bytes
+/// - signer_indices: committee indices of the members that signed
+/// - s_sum: weighted aggregate signature = sum of w_i * partial_i
+/// - pk_provider: committee public keys
+/// - weight_provider: committee weights w_i
+///
+/// Output:
+/// - The total weight of the signers if prod e(w_i * H(m), PK_i) * e(s_sum, -G2) == 1, so the
+///   caller can compare it against its quorum; Err otherwise
+///
+/// Notes:
+/// - Weights must come from trusted state. With caller-supplied weights a single signer could
+///   claim any weight W by submitting W * partial.
+/// - A zero weight is rejected with ScalarOutOfRange, a total weight overflowing u64 with
+///   WeightOverflow, an empty signer list with EmptySignerSet and repeated indices with
+///   DuplicateSigner.
+/// - An identity s_sum or signer key is rejected with PointAtInfinity.
+/// - One extra multiplication syscall per signer on top of verify_a1_with_indices.
+pub fn verify_weighted_a1<M: AsRef<[u8]>>(
+    message: M,
+    signer_indices: &[u16],
+    s_sum: G1Point,
+    pk_provider: &impl PubkeyProvider,
+    weight_provider: &impl WeightProvider,
+) -> Result<u64, BLSError> {
+    if signer_indices.is_empty() {
+        return Err(BLSError::EmptySignerSet);
+    }
+    if !check_no_duplicate_indices(signer_indices) {
+        return Err(BLSError::DuplicateSigner);
+    }
+    if s_sum.is_identity() {
+        return Err(BLSError::PointAtInfinity);
+    }
+
+    let h_g1 = hash_to_curve(message.as_ref())?;
+
+    let mut total = 0u64;
+    let mut pairs = Vec::with_capacity(signer_indices.len() + 1);
+    for &i in signer_indices {
+        let w = weight_provider.weight_by_index(i)?;
+        if w == 0 {
+            return Err(BLSError::ScalarOutOfRange);
+        }
+        total = total.checked_add(w).ok_or(BLSError::WeightOverflow)?;
+        let mut scalar = [0u8; 32];
+        scalar[24..].copy_from_slice(&w.to_be_bytes());
+        let pk = pk_provider.g2_by_index(i)?;
+        if pk.is_identity() {
+            return Err(BLSError::PointAtInfinity);
+        }
+        pairs.push((g1_mul(h_g1, &scalar)?, pk));
+    }
+    pairs.push((s_sum, G2Point(G2_MINUS_ONE)));
+
+    if pairing(&pairs)? {
+        Ok(total)
+    } else {
+        Err(BLSError::BLSVerificationError)
+    }
+}
+
+/// Partial signature for the augmented A1 check, bound to the signer's registered key.
+/// Input:
+/// - sk: the signer's secret scalar
+/// - message: message bytes
+/// - signer_index: the signer's committee index
+/// - pk_provider: committee public keys
+///
+/// Output:
+/// - S_i = sk * H(PK_i || message), as utils::bls_partial_sign_augmented with PK_i looked up
+///
+/// Notes:
+/// - The key at signer_index is trusted to belong to sk; a mismatch just yields a partial that
+///   won't verify.
+pub fn bls_partial_sign_augmented<M: AsRef<[u8]>>(
+    sk: &Fr,
+    message: M,
+    signer_index: u16,
+    pk_provider: &impl PubkeyProvider,
+) -> Result<G1Point, BLSError> {
+    let pk = pk_provider.g2_by_index(signer_index)?;
+    utils::bls_partial_sign_augmented(sk, message, &pk)
+}
+
+/// A1 threshold verify with the augmented scheme, for committees without PoPs.
+/// Input:
+/// - message: message bytes
+/// - signer_indices: committee indices of the members that signed
+/// - s_sum: sum of the signers' partials from bls_partial_sign_augmented
+/// - pk_provider: committee public keys
+///
+/// Output:
+/// - Ok if prod e(H(PK_i || m), PK_i) * e(S_sum, -G2) == 1 for exactly these signers
+///
+/// Notes:
+/// - Binding each key into its hash stops rogue-key attacks without a PoP, at the cost of one
+///   hash-to-curve per signer (see utils::verify_augmented).
+/// - Fast-path partials don't verify here, and augmented partials don't verify in
+///   verify_a1_with_indices.
+/// - Duplicate indices are rejected with DuplicateSigner, and an identity s_sum or signer key
+///   with PointAtInfinity.
+pub fn verify_a1_augmented_with_indices<M: AsRef<[u8]>>(
+    message: M,
+    signer_indices: &[u16],
+    s_sum: G1Point,
+    pk_provider: &impl PubkeyProvider,
+) -> Result<(), BLSError> {
+    if signer_indices.is_empty() {
+        return Err(BLSError::EmptySignerSet);
+    }
+    if !check_no_duplicate_indices(signer_indices) {
+        return Err(BLSError::DuplicateSigner);
+    }
+
+    let pks = signer_indices
+        .iter()
+        .map(|&i| pk_provider.g2_by_index(i))
+        .collect::<Result<Vec<_>, BLSError>>()?;
+    verify_augmented(message, &pks, &s_sum)
+}
+
+/// Aggregate compressed partial signatures and A1-verify them in one call.
+/// Input:
+/// - blob: concatenated compressed G1 partials (32 bytes each), e.g. straight from account data
+/// - signer_indices: committee index of each partial, in the same order as the blob
+/// - message: message bytes
+/// - pk_provider: committee public keys
+///
+/// Output:
+/// - Ok if the sum of the partials verifies for exactly these signers, Err otherwise
+///
+/// Notes:
+/// - The blob must hold exactly signer_indices.len() partials.
+/// - On-chain the partials are summed with k - 1 addition syscalls (see aggregate_partials).
+/// - Same PoP requirement as verify_a1_with_indices.
+pub fn verify_from_partials_blob<M: AsRef<[u8]>>(
+    blob: &[u8],
+    signer_indices: &[u16],
+    message: M,
+    pk_provider: &impl PubkeyProvider,
+) -> Result<(), BLSError> {
+    if signer_indices.is_empty() {
+        return Err(BLSError::EmptySignerSet);
+    }
+    if blob.len() != 32 * signer_indices.len() {
+        let expected = 32 * signer_indices.len();
+        return Err(BLSError::InvalidLength { expected, got: blob.len() });
+    }
+
+    let partials = blob
+        .chunks_exact(32)
+        .map(|c| G1Point::try_from(&G1CompressedPoint(c.try_into().unwrap())))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| BLSError::InvalidSignature)?;
+    let s_sum = aggregate_partials(&partials)?;
+    verify_a1_with_indices(message, signer_indices, s_sum, pk_provider)
+}
+
+/// Provider that consults a list of (index, pubkey) overrides before a base provider.
+struct OverrideProvider<'a, P: PubkeyProvider> {
+    overrides: &'a [(u16, G2Point)],
+    base: &'a P,
+}
+
+impl<P: PubkeyProvider> PubkeyProvider for OverrideProvider<'_, P> {
+    fn g2_by_index(&self, index: u16) -> Result<G2Point, BLSError> {
+        match self.overrides.iter().find(|(i, _)| *i == index) {
+            Some((_, pk)) => Ok(*pk),
+            None => self.base.g2_by_index(index),
+        }
+    }
+}
+
+/// A1 threshold verify with per-index pubkey overrides.
+/// Input:
+/// - message: message bytes
+/// - signer_indices: committee indices of the members that signed
+/// - overrides: (index, pubkey) pairs that replace the base provider's key at that index
+/// - s_sum: aggregated G1 signature
+/// - base_provider: committee public keys for every index without an override
+///
+/// Output:
+/// - Ok if the aggregate verifies, Err otherwise
+///
+/// Notes:
+/// - Useful when a few keys are rotated mid-epoch and the stored committee isn't rebuilt yet.
+/// - Duplicate override indices are rejected with DuplicateSigner, since it would be ambiguous
+///   which key applies.
+pub fn verify_a1_with_overrides<M: AsRef<[u8]>>(
+    message: M,
+    signer_indices: &[u16],
+    overrides: &[(u16, G2Point)],
+    s_sum: G1Point,
+    base_provider: &impl PubkeyProvider,
+) -> Result<(), BLSError> {
+    let override_indices: Vec<u16> = overrides.iter().map(|(i, _)| *i).collect();
+    if !check_no_duplicate_indices(&override_indices) {
+        return Err(BLSError::DuplicateSigner);
+    }
+
+    let provider = OverrideProvider {
+        overrides,
+        base: base_provider,
+    };
+    verify_a1_with_indices(message, signer_indices, s_sum, &provider)
+}
+
+/// Encode signer indices as a bitmap.
+/// Input:
+/// - indices: committee indices of the members that signed
+/// - total: committee size
+///
+/// Output:
+/// - ceil(total / 8) bytes where bit i (byte i / 8, bit i % 8) is set if member i signed
+///
+/// Notes:
+/// - Out-of-range indices are rejected with IndexOutOfBounds and duplicates with DuplicateSigner.
+pub fn indices_to_bitmap(indices: &[u16], total: u16) -> Result<Vec<u8>, BLSError> {
+    let mut bitmap = vec![0u8; (total as usize).div_ceil(8)];
+    for &i in indices {
+        if i >= total {
+            return Err(BLSError::IndexOutOfBounds);
+        }
+        let (byte, bit) = (i as usize / 8, i % 8);
+        if bitmap[byte] & (1 << bit) != 0 {
+            return Err(BLSError::DuplicateSigner);
+        }
+        bitmap[byte] |= 1 << bit;
+    }
+    Ok(bitmap)
+}
+
+/// Decode a bitmap produced by indices_to_bitmap back into ascending signer indices.
+/// Notes:
+/// - The bitmap must be exactly ceil(total / 8) bytes with no bits set at or above total.
+pub fn bitmap_to_indices(bitmap: &[u8], total: u16) -> Result<Vec<u16>, BLSError> {
+    let expected = (total as usize).div_ceil(8);
+    if bitmap.len() != expected {
+        return Err(BLSError::InvalidLength { expected, got: bitmap.len() });
+    }
+
+    let mut indices = Vec::new();
+    for (byte, &b) in bitmap.iter().enumerate() {
+        for bit in 0..8u16 {
+            if b & (1 << bit) == 0 {
+                continue;
+            }
+            let i = byte as u16 * 8 + bit;
+            if i >= total {
+                return Err(BLSError::IndexOutOfBounds);
+            }
+            indices.push(i);
+        }
+    }
+    Ok(indices)
+}
+
+/// A1 threshold verify over a signer bitmap.
+/// Input:
+/// - message: message bytes
+/// - signer_bitmap: bit i (byte i / 8, bit i % 8, little-endian) set if member i signed
+/// - s_sum: aggregated G1 signature
+/// - pk_provider: committee public keys
+///
+/// Output:
+/// - Ok if the aggregate verifies for exactly the set bits, Err otherwise
+///
+/// Notes:
+/// - ceil(n / 8) bytes of instruction data instead of 2 bytes per signer.
+/// - The committee size isn't needed: the bitmap is decoded with bitmap_to_indices for
+///   total = 8 * len (at most u16::MAX), and a bit past the committee fails the provider lookup.
+/// - A bitmap longer than 8192 bytes is rejected with InvalidLength and an all-zero (or
+///   empty) one with EmptySignerSet.
+pub fn verify_a1_with_bitmap<M: AsRef<[u8]>>(
+    message: M,
+    signer_bitmap: &[u8],
+    s_sum: G1Point,
+    pk_provider: &impl PubkeyProvider,
+) -> Result<(), BLSError> {
+    let max = (u16::MAX as usize + 1) / 8;
+    if signer_bitmap.len() > max {
+        return Err(BLSError::InvalidLength { expected: max, got: signer_bitmap.len() });
+    }
+    let total = u16::try_from(signer_bitmap.len() * 8).unwrap_or(u16::MAX);
+    let indices = bitmap_to_indices(signer_bitmap, total)?;
+    verify_a1_with_indices(message, &indices, s_sum, pk_provider)
+}
+
+/// A1 threshold verify over a signer bitmap, returning who signed.
+/// Input:
+/// - message: message bytes
+/// - bitmap: signer bitmap as produced by indices_to_bitmap
+/// - total: committee size
+/// - s_sum: aggregated G1 signature
+/// - pk_provider: committee public keys
+///
+/// Output:
+/// - The ascending signer indices the bitmap indicated, if the aggregate verifies for exactly
+///   that set, Err otherwise
+///
+/// Notes:
+/// - This is the bitmap analogue of verify_a1_with_indices, so the same PoP requirement applies.
+pub fn verify_bitmap_attributing<M: AsRef<[u8]>>(
+    message: M,
+    bitmap: &[u8],
+    total: u16,
+    s_sum: G1Point,
+    pk_provider: &impl PubkeyProvider,
+) -> Result<Vec<u16>, BLSError> {
+    let indices = bitmap_to_indices(bitmap, total)?;
+    verify_a1_with_indices(message, &indices, s_sum, pk_provider)?;
+    Ok(indices)
+}
+
+/// Iterate the set bits of a u128 signer bitmap as ascending committee indices.
+pub fn u128_bitmap_to_indices(bitmap: u128) -> impl Iterator<Item = u16> {
+    let mut rest = bitmap;
+    core::iter::from_fn(move || {
+        if rest == 0 {
+            return None;
+        }
+        let i = rest.trailing_zeros() as u16;
+        // Clear the lowest set bit
+        rest &= rest - 1;
+        Some(i)
+    })
+}
+
+/// Encode signer indices as a u128 bitmap, bit i set if member i signed.
+/// Notes:
+/// - Indices above 127 are rejected with IndexOutOfBounds and duplicates with DuplicateSigner.
+pub fn indices_to_u128_bitmap(indices: &[u16]) -> Result<u128, BLSError> {
+    let mut bitmap = 0u128;
+    for &i in indices {
+        if i > 127 {
+            return Err(BLSError::IndexOutOfBounds);
+        }
+        if bitmap & (1 << i) != 0 {
+            return Err(BLSError::DuplicateSigner);
+        }
+        bitmap |= 1 << i;
+    }
+    Ok(bitmap)
+}
+
+/// A1 threshold verify over a u128 signer bitmap, for committees of at most 128 members.
+/// Input:
+/// - message: message bytes
+/// - bitmap: bit i set if member i signed
+/// - s_sum: aggregated G1 signature
+/// - pk_provider: committee public keys
+///
+/// Output:
+/// - Ok if the aggregate verifies for exactly the set bits, Err otherwise
+///
+/// Notes:
+/// - Fixed 16 bytes of instruction data; the indices are unpacked into a stack buffer and
+///   checked by verify_a1_with_indices.
+/// - A zero bitmap is rejected with EmptySignerSet.
+/// - An identity s_sum or signer key is rejected with PointAtInfinity.
+pub fn verify_a1_with_u128_bitmap<M: AsRef<[u8]>>(
+    message: M,
+    bitmap: u128,
+    s_sum: G1Point,
+    pk_provider: &impl PubkeyProvider,
+) -> Result<(), BLSError> {
+    let mut indices = [0u16; 128];
+    let mut n = 0;
+    for i in u128_bitmap_to_indices(bitmap) {
+        indices[n] = i;
+        n += 1;
+    }
+    verify_a1_with_indices(message, &indices[..n], s_sum, pk_provider)
+}
+
+/// Delta-encode strictly ascending signer indices as LEB128 varint gaps.
+/// Input:
+/// - indices: committee indices of the members that signed, strictly ascending
+///
+/// Output:
+/// - varint(i_0) || varint(i_1 - i_0) || ... || varint(i_k - i_(k-1))
+///
+/// Notes:
+/// - Each varint is 7 bits per byte, low bits first, high bit set on every byte but the last.
+/// - For sparse signer sets this is much smaller than a bitmap: one byte per gap below 128.
+pub fn delta_encode_indices(indices: &[u16]) -> Result<Vec<u8>, BLSError> {
+    if indices.is_empty() || indices.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(BLSError::SerializationError);
+    }
+
+    let mut out = Vec::with_capacity(indices.len());
+    let mut prev = 0u16;
+    for (n, &i) in indices.iter().enumerate() {
+        let mut gap = if n == 0 { i } else { i - prev };
+        while gap >= 0x80 {
+            out.push((gap as u8 & 0x7f) | 0x80);
+            gap >>= 7;
+        }
+        out.push(gap as u8);
+        prev = i;
+    }
+    Ok(out)
+}
+
+/// Decode delta_encode_indices output back into absolute ascending indices.
+/// Notes:
+/// - Rejects truncated or non-minimal varints, zero gaps (so indices strictly increase) and
+///   indices that overflow u16, so every index set has exactly one encoding.
+pub fn delta_decode_indices(deltas: &[u8]) -> Result<Vec<u16>, BLSError> {
+    if deltas.is_empty() {
+        return Err(BLSError::SerializationError);
+    }
+
+    let mut indices = Vec::new();
+    let mut bytes = deltas.iter();
+    while let Some(&first) = bytes.next() {
+        let mut gap = (first & 0x7f) as u32;
+        let mut shift = 0;
+        let mut byte = first;
+        while byte & 0x80 != 0 {
+            shift += 7;
+            byte = *bytes.next().ok_or(BLSError::SerializationError)?;
+            // A u16 fits in 3 varint bytes, and a trailing zero byte is non-minimal
+            if shift > 14 || byte == 0 {
+                return Err(BLSError::SerializationError);
+            }
+            gap |= ((byte & 0x7f) as u32) << shift;
+        }
+
+        let index = match indices.last() {
+            None => gap,
+            Some(_) if gap == 0 => return Err(BLSError::SerializationError),
+            Some(&prev) => prev as u32 + gap,
+        };
+        indices.push(u16::try_from(index).map_err(|_| BLSError::SerializationError)?);
+    }
+    Ok(indices)
+}
+
+/// A1 threshold verify over delta-encoded signer indices (see delta_encode_indices).
+/// Output:
+/// - Ok if the aggregate verifies for exactly the decoded signers, Err otherwise
+///
+/// Notes:
+/// - A third proof format next to index lists and bitmaps, best for large sparse committees.
+/// - Same PoP requirement as verify_a1_with_indices.
+pub fn verify_a1_delta_encoded<M: AsRef<[u8]>>(
+    message: M,
+    deltas: &[u8],
+    s_sum: G1Point,
+    pk_provider: &impl PubkeyProvider,
+) -> Result<(), BLSError> {
+    let indices = delta_decode_indices(deltas)?;
+    verify_a1_with_indices(message, &indices, s_sum, pk_provider)
+}
+
+/// Proof that a committee member did not contribute to an aggregate signature.
+///
+/// It lists every non-signer of the aggregate; the excluded member must be one of them.
+pub struct ExclusionProof {
+    pub excluded: u16,
+    pub non_signers: Vec<u16>,
+}
+
+/// Build an exclusion proof for `excluded` from the aggregate's signer set.
+/// Input:
+/// - signer_indices: committee indices that produced the aggregate
+/// - total: committee size
+/// - excluded: the member to prove absent
+///
+/// Output:
+/// - ExclusionProof whose non_signers are all of 0..total not in signer_indices
+pub fn prove_exclusion(
+    signer_indices: &[u16],
+    total: u16,
+    excluded: u16,
+) -> Result<ExclusionProof, BLSError> {
+    if excluded >= total || signer_indices.contains(&excluded) {
+        return Err(BLSError::SerializationError);
+    }
+    if signer_indices.iter().any(|&i| i >= total) {
+        return Err(BLSError::IndexOutOfBounds);
+    }
+
+    let non_signers = (0..total).filter(|i| !signer_indices.contains(i)).collect();
+    Ok(ExclusionProof {
+        excluded,
+        non_signers,
+    })
+}
+
+/// Verify that `proof.excluded` did not contribute to `s_sum`.
+/// Input:
+/// - message: message bytes
+/// - proof: exclusion proof from prove_exclusion
+/// - full_agg_pk: sum of every committee public key
+/// - s_sum: the aggregate signature
+/// - pk_provider: committee public keys
+///
+/// Output:
+/// - Ok if the exclusion holds, Err otherwise
+///
+/// Predicate:
+/// - proof.excluded is in proof.non_signers (and non_signers has no duplicates),
+/// - PK_excluded is not the identity, so adding it back would change the effective key, and
+/// - e(H(m), full_agg_pk - sum_{j in non_signers} PK_j) == e(S_sum, G2), i.e. s_sum is exactly
+///   the aggregate of the members outside non_signers (see utils::verify_all_except).
+///
+/// With PoP-registered keys this means the aggregate is attributable to a set that does not
+/// contain the excluded member.
+pub fn verify_exclusion<M: AsRef<[u8]>>(
+    message: M,
+    proof: &ExclusionProof,
+    full_agg_pk: &G2Point,
+    s_sum: &G1Point,
+    pk_provider: &impl PubkeyProvider,
+) -> Result<(), BLSError> {
+    if !proof.non_signers.contains(&proof.excluded) {
+        return Err(BLSError::SerializationError);
+    }
+    if !check_no_duplicate_indices(&proof.non_signers) {
+        return Err(BLSError::DuplicateSigner);
+    }
+
+    if pk_provider.g2_by_index(proof.excluded)?.is_identity() {
+        return Err(BLSError::BLSVerificationError);
+    }
+
+    let non_signer_pks = proof
+        .non_signers
+        .iter()
+        .map(|&i| pk_provider.g2_by_index(i))
+        .collect::<Result<Vec<_>, _>>()?;
+    verify_all_except(message, full_agg_pk, &non_signer_pks, s_sum)
+}
+
+/// Proof that one committee member contributed to two different aggregate signatures.
+///
+/// For each aggregate it lists the non-signers; the member must be absent from both lists.
+pub struct SharedSignerProof {
+    pub member: u16,
+    pub non_signers_1: Vec<u16>,
+    pub non_signers_2: Vec<u16>,
+}
+
+/// Build a shared-signer proof for `member` from the signer sets of two aggregates.
+/// Output:
+/// - SharedSignerProof, or Err(SerializationError) if member is not in both signer sets
+pub fn prove_shared_signer(
+    signers_1: &[u16],
+    signers_2: &[u16],
+    total: u16,
+    member: u16,
+) -> Result<SharedSignerProof, BLSError> {
+    if member >= total || !signers_1.contains(&member) || !signers_2.contains(&member) {
+        return Err(BLSError::SerializationError);
+    }
+    if signers_1.iter().chain(signers_2).any(|&i| i >= total) {
+        return Err(BLSError::IndexOutOfBounds);
+    }
+
+    Ok(SharedSignerProof {
+        member,
+        non_signers_1: (0..total).filter(|i| !signers_1.contains(i)).collect(),
+        non_signers_2: (0..total).filter(|i| !signers_2.contains(i)).collect(),
+    })
+}
+
+/// Verify that `proof.member` contributed to both `s_sum_1` (over message_1) and `s_sum_2`
+/// (over message_2).
+/// Input:
+/// - message_1, s_sum_1: the first message and its aggregate signature
+/// - message_2, s_sum_2: the second message and its aggregate signature
+/// - proof: shared-signer proof from prove_shared_signer
+/// - full_agg_pk: sum of every committee public key
+/// - pk_provider: committee public keys
+///
+/// Output:
+/// - Ok if the member is in both signer sets, Err otherwise
+///
+/// Predicate:
+/// - proof.member is in neither non_signers list (and neither list has duplicates),
+/// - PK_member is not the identity, so it actually changes the effective key, and
+/// - for k in {1, 2}: e(H(m_k), full_agg_pk - sum_{j in non_signers_k} PK_j) == e(S_k, G2),
+///   i.e. each aggregate is exactly the sum over the committee minus its non-signers
+///   (see utils::verify_all_except).
+///
+/// With PoP-registered keys this means member ∈ set_1 ∧ member ∈ set_2, where set_k is the
+/// complement of non_signers_k. Costs two pairings of 2 + len(non_signers_k) pairs each.
+pub fn verify_shared_signer<M1: AsRef<[u8]>, M2: AsRef<[u8]>>(
+    message_1: M1,
+    s_sum_1: &G1Point,
+    message_2: M2,
+    s_sum_2: &G1Point,
+    proof: &SharedSignerProof,
+    full_agg_pk: &G2Point,
+    pk_provider: &impl PubkeyProvider,
+) -> Result<(), BLSError> {
+    for non_signers in [&proof.non_signers_1, &proof.non_signers_2] {
+        if non_signers.contains(&proof.member) || !check_no_duplicate_indices(non_signers) {
+            return Err(BLSError::SerializationError);
+        }
+    }
+
+    if pk_provider.g2_by_index(proof.member)?.is_identity() {
+        return Err(BLSError::BLSVerificationError);
+    }
+
+    for (message, s_sum, non_signers) in [
+        (message_1.as_ref(), s_sum_1, &proof.non_signers_1),
+        (message_2.as_ref(), s_sum_2, &proof.non_signers_2),
+    ] {
+        let non_signer_pks = non_signers
+            .iter()
+            .map(|&i| pk_provider.g2_by_index(i))
+            .collect::<Result<Vec<_>, _>>()?;
+        verify_all_except(message, full_agg_pk, &non_signer_pks, s_sum)?;
+    }
+    Ok(())
+}
+
+/// Package a committee's aggregate public key with its members' proofs of possession.
+/// Input:
+/// - members: (PK_i, PoP_i) for each member
+///
+/// Output:
+/// - (sum of every PK_i, the PoPs in member order)
+///
+/// Notes:
+/// - Every PoP is checked first, so a bad member fails the build with BLSVerificationError.
+/// - Duplicate keys are rejected, since the aggregate would count them twice.
+#[cfg(not(target_os = "solana"))]
+pub fn build_committee_proof(
+    members: &[(G2Point, G1Point)],
+) -> Result<(G2Point, Vec<G1Point>), BLSError> {
+    if members.is_empty() {
+        return Err(BLSError::EmptyInput);
+    }
+    for i in 0..members.len() {
+        for j in (i + 1)..members.len() {
+            if members[i].0 .0 == members[j].0 .0 {
+                return Err(BLSError::DuplicateSigner);
+            }
+        }
+    }
+
+    let mut agg_pk: Option<G2Point> = None;
+    for (pk, pop) in members {
+        verify_possession(pk, pop)?;
+        agg_pk = Some(match agg_pk {
+            None => *pk,
+            Some(acc) => acc.checked_add(pk).ok_or(BLSError::SerializationError)?,
+        });
+    }
+    Ok((agg_pk.unwrap(), members.iter().map(|(_, pop)| *pop).collect()))
+}
+
+/// Verify a committee proof from build_committee_proof.
+/// Input:
+/// - agg_pk: the committee's published aggregate public key
+/// - members: the member public keys
+/// - pops: one PoP per member, in the same order
+///
+/// Output:
+/// - Ok if every PoP verifies and agg_pk is exactly the sum of the members, Err otherwise
+///
+/// Notes:
+/// - There is no G2 addition syscall, so the sum is checked inside a pairing against the G1
+///   generator: e(G1, agg_pk) * prod e(G1, -PK_i) == 1. Works on-chain.
+/// - Costs one pairing per PoP plus one with 1 + len(members) pairs.
+/// - No members is rejected with EmptyInput, and a pops count that differs from
+///   members with InvalidLength.
+pub fn verify_committee_proof(
+    agg_pk: &G2Point,
+    members: &[G2Point],
+    pops: &[G1Point],
+) -> Result<(), BLSError> {
+    if members.is_empty() {
+        return Err(BLSError::EmptyInput);
+    }
+    if members.len() != pops.len() {
+        return Err(BLSError::InvalidLength { expected: members.len(), got: pops.len() });
+    }
+    for (pk, pop) in members.iter().zip(pops) {
+        verify_possession(pk, pop)?;
+    }
+
+    let mut g1 = [0u8; 64];
+    g1[31] = 1;
+    g1[63] = 2;
+    let mut pairs = Vec::with_capacity(members.len() + 1);
+    pairs.push((G1Point(g1), *agg_pk));
+    for pk in members {
+        pairs.push((G1Point(g1), g2_neg(pk)));
+    }
+
+    if pairing(&pairs)? {
+        Ok(())
+    } else {
+        Err(BLSError::BLSVerificationError)
+    }
+}
+
+/// A committee's aggregate public key, summed once and reused for every verification.
+/// Notes:
+/// - Meant for a fixed signer set that signs many messages, e.g. an oracle committee. Each
+///   verify is the 2-pair pairing of a single signature (~52k CU) whatever the committee size,
+///   and agrees with verify_fast_aggregate over the same keys on every input.
+/// - Like verify_fast_aggregate, this is only safe if every member key has a PoP.
+/// - Also exported as utils::AggregatePubkey.
+/// - Building needs G2 addition and is off-chain only; store the inner point and wrap it again
+///   on-chain with CommitteeKey(point).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommitteeKey(pub G2Point);
+
+impl CommitteeKey {
+    /// Sum the given member keys. An empty list is Err(EmptySignerSet) and a repeated key
+    /// Err(DuplicateSigner), as in verify_fast_aggregate.
+    #[cfg(not(target_os = "solana"))]
+    pub fn from_keys(pubkeys: &[G2Point]) -> Result<CommitteeKey, BLSError> {
+        if pubkeys.is_empty() {
+            return Err(BLSError::EmptySignerSet);
+        }
+        if !utils::check_no_duplicate_pubkeys(pubkeys) {
+            return Err(BLSError::DuplicateSigner);
+        }
+        Ok(CommitteeKey(pubkeys.iter().sum::<Result<G2Point, BLSError>>()?))
+    }
+
+    /// Sum the keys of the members at `indices`.
+    /// Output:
+    /// - Err(EmptySignerSet) for no indices, Err(DuplicateSigner) if an index repeats
+    #[cfg(not(target_os = "solana"))]
+    pub fn from_provider(
+        indices: &[u16],
+        pk_provider: &impl PubkeyProvider,
+    ) -> Result<CommitteeKey, BLSError> {
+        if !check_no_duplicate_indices(indices) {
+            return Err(BLSError::DuplicateSigner);
+        }
+        let pks = indices
+            .iter()
+            .map(|&i| pk_provider.g2_by_index(i))
+            .collect::<Result<Vec<_>, BLSError>>()?;
+        Ok(CommitteeKey(pks.iter().sum::<Result<G2Point, BLSError>>()?))
+    }
+
+    /// Verify an aggregate signature by every member of the committee.
+    /// Output:
+    /// - Ok if e(H(m), agg_pk) * e(s_sum, -G2) == 1, Err(BLSVerificationError) otherwise
+    pub fn verify<M: AsRef<[u8]>>(&self, message: M, s_sum: &G1Point) -> Result<(), BLSError> {
+        self.0.verify(s_sum, message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        bitmap_to_indices,
+        bls_partial_sign_augmented,
+        build_committee_proof,
+        delta_decode_indices,
+        delta_encode_indices,
+        indices_to_bitmap,
+        indices_to_u128_bitmap,
+        prove_exclusion,
+        prove_shared_signer,
+        u128_bitmap_to_indices,
+        verify_a1_and_attribute,
+        verify_a1_batch,
+        verify_a1_augmented_with_indices,
+        verify_a1_delta_encoded,
+        verify_a1_with_bitmap,
+        verify_a1_with_indices,
+        verify_a1_with_overrides,
+        verify_a1_with_u128_bitmap,
+        verify_bitmap_attributing,
+        verify_committee_proof,
+        verify_exclusion,
+        verify_from_partials_blob,
+        verify_shared_signer,
+        verify_weighted_a1,
+        ArrayPubkeyProvider,
+        CommitteeKey,
+        CompressedSlicePubkeyProvider,
+        PubkeyProvider,
+        Registry,
+        SlicePubkeyProvider,
+        SliceWeightProvider,
+    };
+    use crate::bn254::g1_mul;
+    use crate::errors::BLSError;
+    use crate::g1::{G1CompressedPoint, G1Point};
+    use crate::g2::{G2CompressedPoint, G2Point};
+    use crate::hash::hash_to_curve_pop;
+    use crate::privkey::PrivKey;
+    use crate::scalar::Fr;
+    use crate::test_utils::off_subgroup_compressed;
+    use crate::utils::{aggregate_partials, bls_partial_sign};
+
+    fn committee(n: usize) -> (Vec<PrivKey>, Vec<G2Point>) {
+        let keys: Vec<PrivKey> = (0..n).map(|_| PrivKey::from_random()).collect();
+        let pks = keys.iter().map(|k| G2Point::try_from(k).unwrap()).collect();
+        (keys, pks)
+    }
+
+    fn sign_with(keys: &[PrivKey], indices: &[u16], msg: &[u8]) -> G1Point {
+        let partials: Vec<G1Point> = indices
+            .iter()
+            .map(|&i| Fr::try_from(&keys[i as usize]).unwrap())
+            .map(|sk| bls_partial_sign(&sk, msg).unwrap())
+            .collect();
+        aggregate_partials(&partials).unwrap()
+    }
+
+    fn weighted(keys: &[PrivKey], index: u16, weight: u8, msg: &[u8]) -> G1Point {
+        let mut k = [0u8; 32];
+        k[31] = weight;
+        sign_with(keys, &[index], msg).scalar_mul(&k).unwrap()
+    }
+
+    #[test]
+    fn a1_with_indices_random() {
+        let msg = b"a1-indices";
+        let (keys, pks) = committee(5);
+        let committee = SlicePubkeyProvider(&pks);
+        let indices = [0u16, 2, 4];
+
+        let s_sum = sign_with(&keys, &indices, msg);
+        verify_a1_with_indices(msg, &indices, s_sum, &committee).expect("a1 verify");
+
+        // Claiming a different signer set must fail
+        let err = verify_a1_with_indices(msg, &[0, 2, 3], s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+
+        // Duplicate indices must be rejected
+        let err = verify_a1_with_indices(msg, &[0, 0, 2], s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::DuplicateSigner);
+    }
+
+    #[test]
+    fn a1_rejects_identity_signature_and_key() {
+        let msg = b"a1-identity";
+        let (keys, mut pks) = committee(3);
+        let s_sum = sign_with(&keys, &[0, 1], msg);
+
+        let committee = SlicePubkeyProvider(&pks);
+        let err = verify_a1_with_indices(msg, &[0, 1], G1Point::IDENTITY, &committee).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+
+        pks[2] = G2Point([0u8; 128]);
+        let committee = SlicePubkeyProvider(&pks);
+        let err = verify_a1_with_indices(msg, &[0, 1, 2], s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        verify_a1_with_indices(msg, &[0, 1], s_sum, &committee).expect("a1 verify");
+    }
+
+    #[test]
+    fn a1_rejects_repeated_single_signer() {
+        let msg = b"a1-repeated-signer";
+        let (keys, pks) = committee(4);
+        let committee = SlicePubkeyProvider(&pks);
+
+        // Three copies of member 0's partial, claimed as signers [0, 0, 0]
+        let partial = sign_with(&keys, &[0], msg);
+        let s_sum = aggregate_partials(&[partial, partial, partial]).unwrap();
+        let err = verify_a1_with_indices(msg, &[0, 0, 0], s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::DuplicateSigner);
+
+        // The sorted path past 64 entries catches a duplicate at the very end
+        let mut long: Vec<u16> = (0..100).collect();
+        long.push(99);
+        let err = verify_a1_with_indices(msg, &long, s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::DuplicateSigner);
+    }
+
+    #[test]
+    fn a1_with_overrides_replaces_rotated_key() {
+        let msg = b"a1-overrides";
+        let (mut keys, pks) = committee(4);
+        let committee = SlicePubkeyProvider(&pks);
+
+        // Member 1 rotates its key, but the stored committee still has the old one
+        keys[1] = PrivKey::from_random();
+        let rotated = G2Point::try_from(&keys[1]).unwrap();
+
+        let indices = [0u16, 1, 3];
+        let s_sum = sign_with(&keys, &indices, msg);
+
+        let err = verify_a1_with_indices(msg, &indices, s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+
+        verify_a1_with_overrides(msg, &indices, &[(1, rotated)], s_sum, &committee)
+            .expect("override verify");
+
+        // Duplicate override indices must be rejected
+        let err = verify_a1_with_overrides(
+            msg,
+            &indices,
+            &[(1, rotated), (1, rotated)],
+            s_sum,
+            &committee,
+        )
+        .unwrap_err();
+        assert_eq!(err, BLSError::DuplicateSigner);
+    }
+
+    #[test]
+    fn partials_blob_aggregates_and_verifies() {
+        let msg = b"partials-blob";
+        let (keys, pks) = committee(4);
+        let committee = SlicePubkeyProvider(&pks);
+        let indices = [3u16, 0, 2];
+
+        let mut blob = Vec::new();
+        for &i in &indices {
+            let sk = Fr::try_from(&keys[i as usize]).unwrap();
+            let partial = bls_partial_sign(&sk, msg).unwrap();
+            blob.extend_from_slice(&G1CompressedPoint::try_from(partial).unwrap().0);
+        }
+        verify_from_partials_blob(&blob, &indices, msg, &committee).expect("blob verify");
+
+        // Wrong signer set, and a blob that doesn't match the number of indices
+        let err = verify_from_partials_blob(&blob, &[3, 1, 2], msg, &committee).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+        let err = verify_from_partials_blob(&blob[..64], &indices, msg, &committee).unwrap_err();
+        assert_eq!(err, BLSError::InvalidLength { expected: 96, got: 64 });
+        let err = verify_from_partials_blob(&blob, &indices[..2], msg, &committee).unwrap_err();
+        assert_eq!(err, BLSError::InvalidLength { expected: 64, got: 96 });
+    }
+
+    #[test]
+    fn bitmap_roundtrip_edge_bits() {
+        let total = 20u16;
+        let indices = [0u16, 7, 8, 19];
+
+        let bitmap = indices_to_bitmap(&indices, total).expect("encode");
+        assert_eq!(bitmap, vec![0b1000_0001, 0b0000_0001, 0b0000_1000]);
+        assert_eq!(bitmap_to_indices(&bitmap, total).expect("decode"), indices);
+
+        // Index == total is out of range, duplicates are rejected
+        assert!(indices_to_bitmap(&[total], total).is_err());
+        assert!(indices_to_bitmap(&[3, 3], total).is_err());
+
+        // Wrong length and padding bits set past total are rejected
+        assert!(bitmap_to_indices(&bitmap[..2], total).is_err());
+        assert!(bitmap_to_indices(&[0, 0, 0b0001_0000], total).is_err());
+    }
+
+    #[test]
+    fn a1_with_bitmap_matches_indices() {
+        let msg = b"a1-bitmap";
+        let (keys, pks) = committee(4);
+        let committee = SlicePubkeyProvider(&pks);
+        let s_sum = sign_with(&keys, &[0, 2], msg);
+
+        verify_a1_with_indices(msg, &[0, 2], s_sum, &committee).expect("indices");
+        verify_a1_with_bitmap(msg, &[0b0000_0101], s_sum, &committee).expect("bitmap");
+
+        let err = verify_a1_with_bitmap(msg, &[0b0000_0111], s_sum, &committee);
+        assert_eq!(err.unwrap_err(), BLSError::BLSVerificationError);
+        let err = verify_a1_with_bitmap(msg, &[0, 0], s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::EmptySignerSet);
+        let err = verify_a1_with_bitmap(msg, &[0b0001_0000], s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::IndexOutOfBounds);
+        let err = verify_a1_with_bitmap(msg, &[0u8; 8193], s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::InvalidLength { expected: 8192, got: 8193 });
+    }
+
+    #[test]
+    fn slice_and_array_providers_bounds() {
+        let (keys, pks) = committee(3);
+        let compressed: Vec<G2CompressedPoint> =
+            keys.iter().map(|k| G2CompressedPoint::try_from(k).unwrap()).collect();
+        let array = ArrayPubkeyProvider([pks[0], pks[1], pks[2]]);
+        let slice = SlicePubkeyProvider(&pks);
+        let packed = CompressedSlicePubkeyProvider(&compressed);
+
+        for i in 0..3u16 {
+            let pk = pks[i as usize];
+            assert_eq!(slice.g2_by_index(i).unwrap(), pk);
+            assert_eq!(array.g2_by_index(i).unwrap(), pk);
+            assert_eq!(packed.g2_by_index(i).unwrap(), pk);
+        }
+        assert_eq!(slice.g2_by_index(3).unwrap_err(), BLSError::IndexOutOfBounds);
+        assert_eq!(array.g2_by_index(3).unwrap_err(), BLSError::IndexOutOfBounds);
+        assert_eq!(packed.g2_by_index(u16::MAX).unwrap_err(), BLSError::IndexOutOfBounds);
+    }
+
+    #[test]
+    fn committee_key_verifies_full_committee() {
+        let msg = b"committee-key";
+        let (keys, pks) = committee(100);
+        let provider = SlicePubkeyProvider(&pks);
+        let all: Vec<u16> = (0..100).collect();
+
+        let key = CommitteeKey::from_provider(&all, &provider).expect("committee key");
+        let s_sum = sign_with(&keys, &all, msg);
+        key.verify(msg, &s_sum).expect("verify");
+
+        let err = key.verify(b"other message", &s_sum).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+
+        // An aggregate missing one member doesn't verify against the full key
+        let partial = sign_with(&keys, &all[1..], msg);
+        assert_eq!(key.verify(msg, &partial).unwrap_err(), BLSError::BLSVerificationError);
+
+        let err = CommitteeKey::from_provider(&[], &provider).unwrap_err();
+        assert_eq!(err, BLSError::EmptySignerSet);
+        let err = CommitteeKey::from_provider(&[1, 1], &provider).unwrap_err();
+        assert_eq!(err, BLSError::DuplicateSigner);
+    }
+
+    #[test]
+    fn committee_key_from_keys_agrees_with_fast_aggregate() {
+        let msg = b"agg-pk";
+        let (keys, pks) = committee(6);
+        let all: Vec<u16> = (0..6).collect();
+        let s_sum = sign_with(&keys, &all, msg);
+        let single = sign_with(&keys, &[0], msg);
+
+        let key = CommitteeKey::from_keys(&pks).expect("committee key");
+        assert_eq!(key, CommitteeKey::from_provider(&all, &SlicePubkeyProvider(&pks)).unwrap());
+        for (m, sig) in [(&msg[..], s_sum), (b"other", s_sum), (msg, single)] {
+            assert_eq!(key.verify(m, &sig), crate::utils::verify_fast_aggregate(m, &pks, &sig));
+        }
+
+        let err = CommitteeKey::from_keys(&[]).unwrap_err();
+        assert_eq!(err, BLSError::EmptySignerSet);
+        let err = CommitteeKey::from_keys(&[pks[0], pks[0]]).unwrap_err();
+        assert_eq!(err, BLSError::DuplicateSigner);
+    }
+
+    #[test]
+    fn weighted_a1_returns_signer_weight() {
+        let msg = b"weighted-a1";
+        let (keys, pks) = committee(3);
+        let committee = SlicePubkeyProvider(&pks);
+        let stake = SliceWeightProvider(&[10, 20, 30]);
+
+        // Members 0 and 2 sign with weights 10 and 30
+        let s_sum = weighted(&keys, 0, 10, msg) + weighted(&keys, 2, 30, msg);
+        let weight = verify_weighted_a1(msg, &[0, 2], s_sum, &committee, &stake);
+        assert_eq!(weight.expect("weighted verify"), 40);
+
+        // An unweighted aggregate, or one built for other weights, fails
+        let plain = sign_with(&keys, &[0, 2], msg);
+        let err = verify_weighted_a1(msg, &[0, 2], plain, &committee, &stake).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+        let other = weighted(&keys, 0, 20, msg) + weighted(&keys, 2, 20, msg);
+        let err = verify_weighted_a1(msg, &[0, 2], other, &committee, &stake).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+
+        let err = verify_weighted_a1(msg, &[0, 0], s_sum, &committee, &stake).unwrap_err();
+        assert_eq!(err, BLSError::DuplicateSigner);
+        let err = verify_weighted_a1(msg, &[0, 3], s_sum, &committee, &stake).unwrap_err();
+        assert_eq!(err, BLSError::IndexOutOfBounds);
+        let zero = SliceWeightProvider(&[10, 0, 30]);
+        let s_one = weighted(&keys, 1, 1, msg);
+        let err = verify_weighted_a1(msg, &[1], s_one, &committee, &zero).unwrap_err();
+        assert_eq!(err, BLSError::ScalarOutOfRange);
+    }
+
+    #[test]
+    fn weighted_a1_rejects_scaled_single_partial() {
+        let msg = b"weighted-a1-scaled";
+        let (keys, pks) = committee(3);
+        let committee = SlicePubkeyProvider(&pks);
+        let stake = SliceWeightProvider(&[10, 20, 30]);
+
+        // A lone signer scaling its partial to the full quorum weight gains nothing: the weight
+        // is read from the stake table, not from the submission
+        let forged = weighted(&keys, 0, 60, msg);
+        let err = verify_weighted_a1(msg, &[0], forged, &committee, &stake).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+        let honest = weighted(&keys, 0, 10, msg);
+        assert_eq!(verify_weighted_a1(msg, &[0], honest, &committee, &stake), Ok(10));
+    }
+
+    #[test]
+    fn a1_augmented_does_not_mix_with_fast_path() {
+        let msg = b"a1-augmented";
+        let (keys, pks) = committee(4);
+        let committee = SlicePubkeyProvider(&pks);
+        let signers = [1u16, 3];
+
+        let partials: Vec<G1Point> = signers
+            .iter()
+            .map(|&i| {
+                let sk = Fr::try_from(&keys[i as usize]).unwrap();
+                bls_partial_sign_augmented(&sk, msg, i, &committee).unwrap()
+            })
+            .collect();
+        let augmented = aggregate_partials(&partials).unwrap();
+        verify_a1_augmented_with_indices(msg, &signers, augmented, &committee).expect("augmented");
+
+        // Each scheme's aggregate fails the other's check
+        let fast = sign_with(&keys, &signers, msg);
+        verify_a1_with_indices(msg, &signers, fast, &committee).expect("fast");
+        let err = verify_a1_augmented_with_indices(msg, &signers, fast, &committee).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+        let err = verify_a1_with_indices(msg, &signers, augmented, &committee).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+
+        let err = verify_a1_augmented_with_indices(msg, &[1, 1], augmented, &committee);
+        assert_eq!(err.unwrap_err(), BLSError::DuplicateSigner);
+    }
+
+    #[test]
+    fn a1_and_attribute_returns_sorted_signers() {
+        let msg = b"a1-attribute";
+        let (keys, pks) = committee(4);
+        let committee = SlicePubkeyProvider(&pks);
+        let s_sum = sign_with(&keys, &[0, 1, 2], msg);
+
+        let who = verify_a1_and_attribute(msg, &[2, 0, 1], s_sum, &committee).expect("verify");
+        assert_eq!(who, vec![0, 1, 2]);
+
+        let err = verify_a1_and_attribute(msg, &[2, 0, 3], s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+    }
+
+    #[test]
+    fn a1_batch_accepts_valid_and_rejects_tampered() {
+        let (keys, pks) = committee(5);
+        let committee = SlicePubkeyProvider(&pks);
+        let messages: [&[u8]; 4] = [b"feed-0", b"feed-1", b"feed-2", b"feed-3"];
+        let signers: [&[u16]; 4] = [&[0, 1, 2], &[1, 3], &[4], &[0, 2, 3, 4]];
+        let sigs: Vec<G1Point> = messages
+            .iter()
+            .zip(signers)
+            .map(|(m, idx)| sign_with(&keys, idx, m))
+            .collect();
+
+        verify_a1_batch(&messages, &sigs, &signers, &committee).expect("batch");
+
+        let mut tampered = messages;
+        tampered[2] = b"feed-2'";
+        let err = verify_a1_batch(&tampered, &sigs, &signers, &committee).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+
+        // Swapping two signatures keeps the sum but breaks the per-message equations
+        let mut swapped = sigs.clone();
+        swapped.swap(0, 1);
+        let err = verify_a1_batch(&messages, &swapped, &signers, &committee).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+
+        let err = verify_a1_batch(&messages[..3], &sigs, &signers, &committee).unwrap_err();
+        assert_eq!(err, BLSError::InvalidLength { expected: 3, got: 4 });
+        let err = verify_a1_batch(&messages, &sigs, &signers[..2], &committee).unwrap_err();
+        assert_eq!(err, BLSError::InvalidLength { expected: 4, got: 2 });
+    }
+
+    #[test]
+    fn a1_variants_reject_identity() {
+        let msg = b"a1-identity";
+        let (keys, mut pks) = committee(3);
+        let sig = sign_with(&keys, &[0], msg);
+        let zero = G1Point::IDENTITY;
+
+        // An identity signature, or an identity committee slot, never verifies
+        let committee = SlicePubkeyProvider(&pks);
+        let err = verify_a1_batch(&[msg], &[zero], &[&[0]], &committee).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        let stake = SliceWeightProvider(&[1, 1, 1]);
+        let err = verify_weighted_a1(msg, &[0], zero, &committee, &stake).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        let err = verify_a1_augmented_with_indices(msg, &[0], zero, &committee).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        let err = verify_a1_with_u128_bitmap(msg, 0b1, zero, &committee).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+
+        pks[1] = G2Point([0u8; 128]);
+        let committee = SlicePubkeyProvider(&pks);
+        let err = verify_a1_batch(&[msg], &[sig], &[&[0, 1]], &committee).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        let err = verify_weighted_a1(msg, &[0, 1], sig, &committee, &stake).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        let err = verify_a1_augmented_with_indices(msg, &[0, 1], sig, &committee).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        let err = verify_a1_with_u128_bitmap(msg, 0b11, sig, &committee).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        verify_a1_batch(&[msg], &[sig], &[&[0]], &committee).expect("untouched slot");
+    }
+
+    #[test]
+    fn specific_error_variants() {
+        let msg = b"error-variants";
+        let (keys, pks) = committee(3);
+        let provider = SlicePubkeyProvider(&pks);
+        let s_sum = sign_with(&keys, &[0, 1], msg);
+
+        let err = verify_a1_with_indices(msg, &[], s_sum, &provider).unwrap_err();
+        assert_eq!(err, BLSError::EmptySignerSet);
+        let err = verify_a1_with_indices(msg, &[0, 3], s_sum, &provider).unwrap_err();
+        assert_eq!(err, BLSError::IndexOutOfBounds);
+        assert_eq!(indices_to_bitmap(&[3], 3).unwrap_err(), BLSError::IndexOutOfBounds);
+        assert_eq!(indices_to_bitmap(&[1, 1], 3).unwrap_err(), BLSError::DuplicateSigner);
+        assert_eq!(indices_to_u128_bitmap(&[200]).unwrap_err(), BLSError::IndexOutOfBounds);
+        let err = verify_a1_batch(&[], &[], &[], &provider).unwrap_err();
+        assert_eq!(err, BLSError::EmptyInput);
+        assert_eq!(build_committee_proof(&[]).unwrap_err(), BLSError::EmptyInput);
+        assert_eq!(verify_committee_proof(&pks[0], &[], &[]).unwrap_err(), BLSError::EmptyInput);
+
+        let heavy = SliceWeightProvider(&[u64::MAX, 1, 1]);
+        let err = verify_weighted_a1(msg, &[0, 1], s_sum, &provider, &heavy).unwrap_err();
+        assert_eq!(err, BLSError::WeightOverflow);
+
+        let blob: Vec<u8> =
+            pks.iter().flat_map(|pk| G2CompressedPoint::try_from(pk).unwrap().0).collect();
+        let registry = Registry::new(&blob).unwrap();
+        assert_eq!(registry.g2_by_index(3).unwrap_err(), BLSError::IndexOutOfBounds);
+
+        // A partial that doesn't decompress is an invalid signature
+        let err = verify_from_partials_blob(&[0xff; 64], &[0, 1], msg, &provider).unwrap_err();
+        assert_eq!(err, BLSError::InvalidSignature);
+    }
+
+    #[test]
+    fn a1_with_u128_bitmap_matches_indices() {
+        let msg = b"a1-u128-bitmap";
+        let (keys, pks) = committee(5);
+        let committee = SlicePubkeyProvider(&pks);
+        let s_sum = sign_with(&keys, &[1, 3, 4], msg);
+
+        let bitmap = indices_to_u128_bitmap(&[4, 1, 3]).expect("encode");
+        assert_eq!(bitmap, 0b1_1010);
+        let indices: Vec<u16> = u128_bitmap_to_indices(bitmap).collect();
+        assert_eq!(indices, vec![1, 3, 4]);
+
+        verify_a1_with_u128_bitmap(msg, bitmap, s_sum, &committee).expect("bitmap");
+        verify_a1_with_indices(msg, &indices, s_sum, &committee).expect("indices");
+
+        let err = verify_a1_with_u128_bitmap(msg, 0b1010, s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+        let err = verify_a1_with_u128_bitmap(msg, 0, s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::EmptySignerSet);
+    }
+
+    #[test]
+    fn u128_bitmap_edges() {
+        assert_eq!(indices_to_u128_bitmap(&[0, 127]).expect("encode"), 1 | (1 << 127));
+        assert_eq!(u128_bitmap_to_indices(u128::MAX).count(), 128);
+        assert_eq!(u128_bitmap_to_indices(1 << 127).collect::<Vec<_>>(), vec![127]);
+        assert!(indices_to_u128_bitmap(&[128]).is_err());
+        assert!(indices_to_u128_bitmap(&[5, 5]).is_err());
+    }
+
+    #[test]
+    fn bitmap_attributing_returns_set_bits() {
+        let msg = b"bitmap-attributing";
+        let (keys, pks) = committee(10);
+        let committee = SlicePubkeyProvider(&pks);
+        let signers = [1u16, 4, 8, 9];
+        let s_sum = sign_with(&keys, &signers, msg);
+
+        let bitmap = indices_to_bitmap(&signers, 10).expect("encode");
+        let who = verify_bitmap_attributing(msg, &bitmap, 10, s_sum, &committee)
+            .expect("verify");
+        assert_eq!(who, signers);
+
+        // A bitmap naming a different set returns no attribution
+        let other = indices_to_bitmap(&[1, 4, 8], 10).expect("encode");
+        let err = verify_bitmap_attributing(msg, &other, 10, s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+    }
+
+    #[test]
+    fn delta_encoding_roundtrip() {
+        let indices = [3u16, 4, 130, 300, u16::MAX];
+        let deltas = delta_encode_indices(&indices).expect("encode");
+        // 3, 1, 126 are one byte each; 170 and 65235 need two and three
+        assert_eq!(deltas.len(), 3 + 2 + 3);
+        assert_eq!(delta_decode_indices(&deltas).expect("decode"), indices);
+
+        // Encoder requires strictly ascending input
+        assert!(delta_encode_indices(&[4, 4]).is_err());
+        assert!(delta_encode_indices(&[5, 2]).is_err());
+
+        // Zero gap, truncated varint, non-minimal varint, u16 overflow
+        assert!(delta_decode_indices(&[3, 0]).is_err());
+        assert!(delta_decode_indices(&[0x83]).is_err());
+        assert!(delta_decode_indices(&[0x83, 0x00]).is_err());
+        assert!(delta_decode_indices(&[0xff, 0xff, 0x03, 0x01]).is_err());
+    }
+
+    #[test]
+    fn a1_delta_encoded_verifies() {
+        let msg = b"a1-delta";
+        let (keys, pks) = committee(6);
+        let committee = SlicePubkeyProvider(&pks);
+        let indices = [1u16, 2, 5];
+        let s_sum = sign_with(&keys, &indices, msg);
+
+        let deltas = delta_encode_indices(&indices).expect("encode");
+        assert_eq!(deltas, vec![1, 1, 3]);
+        verify_a1_delta_encoded(msg, &deltas, s_sum, &committee).expect("delta verify");
+
+        let err = verify_a1_delta_encoded(msg, &[1, 1, 2], s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+    }
+
+    #[test]
+    fn exclusion_proof_valid_and_false() {
+        let msg = b"exclusion";
+        let (keys, pks) = committee(5);
+        let committee = SlicePubkeyProvider(&pks);
+        let full_agg_pk = committee.0[1..].iter().fold(committee.0[0], |acc, pk| acc + *pk);
+
+        let signers = [0u16, 1, 3];
+        let s_sum = sign_with(&keys, &signers, msg);
+
+        // Member 2 did not sign
+        let proof = prove_exclusion(&signers, 5, 2).expect("prove");
+        verify_exclusion(msg, &proof, &full_agg_pk, &s_sum, &committee).expect("exclusion");
+
+        // Member 1 signed, so it can't be proven absent
+        assert!(prove_exclusion(&signers, 5, 1).is_err());
+
+        // A forged proof claiming member 1 as a non-signer fails the pairing check
+        let forged = super::ExclusionProof {
+            excluded: 1,
+            non_signers: vec![1, 2, 4],
+        };
+        let err = verify_exclusion(msg, &forged, &full_agg_pk, &s_sum, &committee).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+    }
+
+    #[test]
+    fn shared_signer_overlapping_and_disjoint() {
+        let (m1, m2) = (b"shared-1", b"shared-2");
+        let (keys, pks) = committee(6);
+        let committee = SlicePubkeyProvider(&pks);
+        let full_agg_pk = committee.0[1..].iter().fold(committee.0[0], |acc, pk| acc + *pk);
+
+        let set_1 = [0u16, 2, 3];
+        let set_2 = [2u16, 4, 5];
+        let s1 = sign_with(&keys, &set_1, m1);
+        let s2 = sign_with(&keys, &set_2, m2);
+
+        // Member 2 signed both
+        let proof = prove_shared_signer(&set_1, &set_2, 6, 2).expect("prove");
+        verify_shared_signer(m1, &s1, m2, &s2, &proof, &full_agg_pk, &committee)
+            .expect("shared signer");
+
+        // Member 0 only signed the first, so there is no proof to build
+        assert!(prove_shared_signer(&set_1, &set_2, 6, 0).is_err());
+
+        // Disjoint sets: a forged proof claiming member 0 in set 2 fails the pairing check
+        let s3 = sign_with(&keys, &[4, 5], m2);
+        let forged = super::SharedSignerProof {
+            member: 0,
+            non_signers_1: vec![1, 4, 5],
+            non_signers_2: vec![1, 2, 3],
+        };
+        let err = verify_shared_signer(m1, &s1, m2, &s3, &forged, &full_agg_pk, &committee)
+            .unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+    }
+
+    #[test]
+    fn committee_proof_rejects_bad_pop() {
+        let keys: Vec<PrivKey> = (0..3).map(|_| PrivKey::from_random()).collect();
+        let pks: Vec<G2Point> = keys.iter().map(|k| G2Point::try_from(k).unwrap()).collect();
+        let members: Vec<(G2Point, G1Point)> = keys
+            .iter()
+            .zip(&pks)
+            .map(|(k, pk)| (*pk, k.prove_possession().unwrap()))
+            .collect();
+
+        let (agg_pk, pops) = build_committee_proof(&members).expect("build");
+        verify_committee_proof(&agg_pk, &pks, &pops).expect("verify");
+
+        // An aggregate that leaves out a member is not the genuine sum
+        let partial_agg = pks[0] + pks[1];
+        let err = verify_committee_proof(&partial_agg, &pks, &pops).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+
+        // Member 2's PoP made with member 1's key breaks both build and verify
+        let mut bad = members.clone();
+        bad[2].1 = g1_mul(hash_to_curve_pop(&pks[2]).unwrap(), &keys[1].0).unwrap();
+        assert!(matches!(build_committee_proof(&bad), Err(BLSError::BLSVerificationError)));
+        let bad_pops: Vec<G1Point> = bad.iter().map(|(_, p)| *p).collect();
+        let err = verify_committee_proof(&agg_pk, &pks, &bad_pops).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+
+        let err = verify_committee_proof(&agg_pk, &pks, &pops[..2]).unwrap_err();
+        assert_eq!(err, BLSError::InvalidLength { expected: 3, got: 2 });
+    }
+
+    #[test]
+    fn registry_validate_finds_invalid_key() {
+        let msg = b"registry";
+        let keys: Vec<PrivKey> = (0..4).map(|_| PrivKey::from_random()).collect();
+        let mut blob = Vec::new();
+        for k in &keys {
+            blob.extend_from_slice(&G2CompressedPoint::try_from(k).unwrap().0);
+        }
+
+        let registry = Registry::new(&blob).expect("registry");
+        registry.validate().expect("valid registry");
+        assert_eq!(registry.first_invalid(), None);
+
+        // The registry doubles as a pubkey provider
+        let s_sum = sign_with(&keys, &[1, 2], msg);
+        verify_a1_with_indices(msg, &[1, 2], s_sum, &registry).expect("a1 verify");
+
+        // Replace member 2 with an on-curve point outside the subgroup
+        blob[128..192].copy_from_slice(&off_subgroup_compressed().0);
+        let registry = Registry::new(&blob).expect("registry");
+        assert_eq!(registry.validate(), Err((Some(2), BLSError::InvalidPublicKey)));
+        assert_eq!(registry.first_invalid(), Some(2));
+
+        // Garbage that doesn't decompress at all
+        blob[64..128].copy_from_slice(&[0xff; 64]);
+        let registry = Registry::new(&blob).expect("registry");
+        assert_eq!(registry.validate(), Err((Some(1), BLSError::G2PointDecompressionError)));
+        assert_eq!(registry.first_invalid(), Some(1));
+
+        // The identity passes the subgroup check but is never a usable key
+        let identity = G2CompressedPoint::try_from(&G2Point([0u8; 128])).expect("identity");
+        blob[..64].copy_from_slice(&identity.0);
+        let registry = Registry::new(&blob).expect("registry");
+        assert_eq!(registry.validate(), Err((Some(0), BLSError::PointAtInfinity)));
+        assert_eq!(registry.first_invalid(), Some(0));
+
+        assert!(Registry::new(&blob[..100]).is_err());
+    }
+}
@@ -37,105 +37,132 @@
 // Result:
 //   Valid and attributable to indices {1, 3} because only PK1 and PK3 were used
 
+use crate::bn254::{g1_add, g1_mul, g2_neg, pairing};
 use crate::consts::G2_MINUS_ONE;
 use crate::errors::BLSError;
-use crate::g1::G1Point;
-use crate::g2::G2Point;
-use crate::hash::hash_to_curve;
-
-use solana_bn254::prelude::{
-    alt_bn128_addition, alt_bn128_multiplication, alt_bn128_pairing,
-};
+use crate::g1::{G1CompressedPoint, G1Point};
+use crate::g2::{G2CompressedPoint, G2Point, PubKeyInput};
+use crate::hash::{hash_to_curve, HashToCurve, Sha256, DEFAULT_DST};
+#[cfg(not(target_os = "solana"))]
+use crate::hash::{hash_to_curve_g2, MIN_PK_DST};
+use crate::privkey::{check_security_acknowledged, check_signing_scalar};
+use crate::scalar::Fr;
 
 /// Compute a BLS partial signature in G1.
 /// Input:
-/// - sk: 32 byte big-endian secret key
+/// - sk: secret scalar; zero or a value not below r is Err(ScalarOutOfRange)
 /// - message: message bytes
+///
 /// Output:
 /// - S_i = H(message) * sk_i as a G1 point (uncompressed 64 bytes)
+///
 /// Notes:
 /// - This is used for fast aggregate verify. See verify_fast_aggregate below.
 /// - For production, add domain separation to your message.
-/// - BN254 has about 100-bit security.
+/// - BN254 has about 100-bit security (see consts::SECURITY_BITS).
 pub fn bls_partial_sign(
-    sk: &[u8; 32],
+    sk: &Fr,
     message: impl AsRef<[u8]>,
 ) -> Result<G1Point, BLSError> {
-    let h_g1 = hash_to_curve(message)?.0;
+    bls_partial_sign_with_dst(sk, message, DEFAULT_DST)
+}
 
-    let mut inbuf = [0u8; 96];
-    inbuf[..64].copy_from_slice(&h_g1);
-    inbuf[64..].copy_from_slice(&sk[..]);
+/// bls_partial_sign under a caller-chosen domain tag (see hash::hash_to_curve_with_dst).
+/// Verify with verify_fast_aggregate_with_dst and the same tag.
+pub fn bls_partial_sign_with_dst(
+    sk: &Fr,
+    message: impl AsRef<[u8]>,
+    dst: &[u8],
+) -> Result<G1Point, BLSError> {
+    bls_partial_sign_with_hasher::<Sha256>(sk, message, dst)
+}
 
-    let out = alt_bn128_multiplication(&inbuf).map_err(|_| BLSError::AltBN128MulError)?;
-    let mut sig = [0u8; 64];
-    sig.copy_from_slice(&out[..64]);
-    Ok(G1Point(sig))
+/// bls_partial_sign with the message hash chosen by `H` (see hash::HashToCurve).
+/// Verify with verify_fast_aggregate_with_hasher over the same `H` and tag.
+pub fn bls_partial_sign_with_hasher<H: HashToCurve>(
+    sk: &Fr,
+    message: impl AsRef<[u8]>,
+    dst: &[u8],
+) -> Result<G1Point, BLSError> {
+    check_security_acknowledged()?;
+    check_signing_scalar(&sk.0)?;
+    g1_mul(H::try_hash_to_curve(message.as_ref(), dst)?, &sk.0)
+}
+
+/// bls_partial_sign over hash::hash_to_curve_keccak (keccak feature).
+/// Verify with verify_fast_aggregate_keccak and the same tag.
+#[cfg(feature = "keccak")]
+pub fn bls_partial_sign_keccak(
+    sk: &Fr,
+    message: impl AsRef<[u8]>,
+    dst: &[u8],
+) -> Result<G1Point, BLSError> {
+    bls_partial_sign_with_hasher::<crate::hash::Keccak256>(sk, message, dst)
 }
 
 /// Compute an augmented BLS partial signature in G1.
 /// Input:
-/// - sk: 32 byte big-endian secret key
+/// - sk: secret scalar; zero or a value not below r is Err(ScalarOutOfRange)
 /// - message: message bytes
 /// - signer_pk_g2: the signer's public key in G2 (uncompressed 128 bytes)
+///
 /// Output:
 /// - S_i = H(pk_i || message) * sk_i as a G1 point
+///
 /// Notes:
 /// - Augmented signing binds the public key into the hash. This prevents rogue-key attacks
 ///   without requiring a proof of possession (PoP).
 /// - This is slower than fast aggregate verify because the verifer must hash per signer.
 pub fn bls_partial_sign_augmented(
-    sk: &[u8; 32],
+    sk: &Fr,
     message: impl AsRef<[u8]>,
     signer_pk_g2: &G2Point,
 ) -> Result<G1Point, BLSError> {
+    check_security_acknowledged()?;
+    check_signing_scalar(&sk.0)?;
     let mut m = Vec::with_capacity(3 + 128 + message.as_ref().len());
     m.extend_from_slice(&signer_pk_g2.0);
     m.extend_from_slice(message.as_ref());
 
-    let h_g1 = hash_to_curve(&m)?.0;
-
-    let mut inbuf = [0u8; 96];
-    inbuf[..64].copy_from_slice(&h_g1);
-    inbuf[64..].copy_from_slice(&sk[..]);
-
-    let out = alt_bn128_multiplication(&inbuf).map_err(|_| BLSError::AltBN128MulError)?;
-    let mut sig = [0u8; 64];
-    sig.copy_from_slice(&out[..64]);
-    Ok(G1Point(sig))
+    g1_mul(hash_to_curve(&m)?, &sk.0)
 }
 
 /// Sum a list of partial signatures in G1.
 /// Input:
 /// - partials: list of S_i points
+///
 /// Output:
 /// - S_sum = sum of all S_i (G1 point)
+///
+/// Notes:
+/// - Delegates to `Sum for Result<G1Point, BLSError>`; an empty list is Err(EmptySignerSet).
+/// - The addition precompile only takes two points, so on-chain this is k - 1 syscalls.
+/// - Off-chain the sum is accumulated in projective coordinates and normalized once, which
+///   avoids a field inversion per addition (see benches/aggregate.rs). The output is identical.
 pub fn aggregate_partials(partials: &[G1Point]) -> Result<G1Point, BLSError> {
-    if partials.is_empty() {
-        return Err(BLSError::SerializationError);
-    }
-    let mut acc = partials[0].0;
+    partials.iter().sum()
+}
 
-    for s in &partials[1..] {
-        let mut inbuf = [0u8; 128];
-        inbuf[..64].copy_from_slice(&acc);
-        inbuf[64..].copy_from_slice(&s.0);
-        let out = alt_bn128_addition(&inbuf).map_err(|_| BLSError::AltBN128AddError)?;
-        acc.copy_from_slice(&out[..64]);
-    }
-    Ok(G1Point(acc))
+/// aggregate_partials over any iterator, e.g. partials decoded lazily from account data, without
+/// collecting them into a Vec first.
+/// Notes:
+/// - Same fold and the same Err(EmptySignerSet) for no partials as aggregate_partials.
+pub fn aggregate_partials_iter<I: IntoIterator<Item = G1Point>>(
+    partials: I,
+) -> Result<G1Point, BLSError> {
+    partials.into_iter().sum()
 }
 
 /// Helper to check that a list of G2 pubkeys has no duplicates.
-fn check_no_duplicate_pubkeys(pubkeys: &[G2Point]) -> bool {
-    for i in 0..pubkeys.len() {
-        for j in (i + 1)..pubkeys.len() {
-            if pubkeys[i].0 == pubkeys[j].0 {
-                return false;
-            }
-        }
-    }
-    true
+pub(crate) fn check_no_duplicate_pubkeys(pubkeys: &[G2Point]) -> bool {
+    check_no_duplicate_keys(pubkeys.iter().map(|pk| &pk.0).collect())
+}
+
+/// Shared body of the duplicate checks, over references to the uncompressed point bytes.
+/// Sorts the references, so it's O(n log n) and only allocates n pointers.
+fn check_no_duplicate_keys<const N: usize>(mut sorted: Vec<&[u8; N]>) -> bool {
+    sorted.sort_unstable();
+    sorted.windows(2).all(|w| w[0] != w[1])
 }
 
 /// Fast aggregate verify for BLS multi-signatures.
@@ -143,45 +170,173 @@ fn check_no_duplicate_pubkeys(pubkeys: &[G2Point]) -> bool {
 /// - message: message bytes
 /// - signer_pubkeys: the exact G2 public keys that supposedly signed
 /// - s_sum: aggregated G1 signature = sum of signers' partial signatures
+///
 /// Output:
 /// - Ok if the aggregate verifies, Err otherwise
+///
 /// Important:
 /// - This fast path is only safe if every public key is registered with a proof of possession (PoP).
 /// - Without PoP, a malicious signer can craft a rogue key and make it look like others signed.
+/// - Produce the PoP with PrivKey::prove_possession and check it with g2::verify_possession
+///   when the key is registered.
+/// - An identity s_sum or signer key is rejected with PointAtInfinity.
 pub fn verify_fast_aggregate<M: AsRef<[u8]>>(
     message: M,
     signer_pubkeys: &[G2Point],
     s_sum: &G1Point,
+) -> Result<(), BLSError> {
+    verify_fast_aggregate_with_dst(message, signer_pubkeys, s_sum, DEFAULT_DST)
+}
+
+/// verify_fast_aggregate for partials made with bls_partial_sign_with_dst under `dst`.
+/// Same PoP requirement as verify_fast_aggregate.
+pub fn verify_fast_aggregate_with_dst<M: AsRef<[u8]>>(
+    message: M,
+    signer_pubkeys: &[G2Point],
+    s_sum: &G1Point,
+    dst: &[u8],
+) -> Result<(), BLSError> {
+    verify_fast_aggregate_with_hasher::<Sha256, M>(message, signer_pubkeys, s_sum, dst)
+}
+
+/// verify_fast_aggregate with the message hash chosen by `H`, for partials made with
+/// bls_partial_sign_with_hasher over the same `H` and tag.
+/// Same PoP requirement as verify_fast_aggregate.
+pub fn verify_fast_aggregate_with_hasher<H: HashToCurve, M: AsRef<[u8]>>(
+    message: M,
+    signer_pubkeys: &[G2Point],
+    s_sum: &G1Point,
+    dst: &[u8],
+) -> Result<(), BLSError> {
+    fast_aggregate_check(signer_pubkeys, s_sum, || {
+        H::try_hash_to_curve(message.as_ref(), dst)
+    })
+}
+
+/// verify_fast_aggregate for partials made with bls_partial_sign_keccak (keccak feature).
+/// Same PoP requirement as verify_fast_aggregate.
+#[cfg(feature = "keccak")]
+pub fn verify_fast_aggregate_keccak<M: AsRef<[u8]>>(
+    message: M,
+    signer_pubkeys: &[G2Point],
+    s_sum: &G1Point,
+    dst: &[u8],
+) -> Result<(), BLSError> {
+    use crate::hash::Keccak256;
+    verify_fast_aggregate_with_hasher::<Keccak256, M>(message, signer_pubkeys, s_sum, dst)
+}
+
+/// The fast aggregate pairing check, with the message hash supplied by the caller.
+/// The signer list is validated before the (comparatively expensive) hash is computed.
+fn fast_aggregate_check(
+    signer_pubkeys: &[G2Point],
+    s_sum: &G1Point,
+    hash: impl FnOnce() -> Result<G1Point, BLSError>,
 ) -> Result<(), BLSError> {
     let k = signer_pubkeys.len();
     if k == 0 {
-        return Err(BLSError::SerializationError);
+        return Err(BLSError::EmptySignerSet);
     }
     if !check_no_duplicate_pubkeys(signer_pubkeys) {
-        return Err(BLSError::SerializationError);
+        return Err(BLSError::DuplicateSigner);
+    }
+
+    // The identity key would make e(H(m), PK_i) trivially one, and the identity signature
+    // would do the same for e(S, -G2)
+    if s_sum.is_identity() || signer_pubkeys.iter().any(G2Point::is_identity) {
+        return Err(BLSError::PointAtInfinity);
     }
 
     // Hash message to G1 once
-    let h_g1 = hash_to_curve(message.as_ref())?.0;
+    let h_g1 = hash()?;
 
-    // Build input for pairing:
+    // Build pairing pairs:
     // For each signer: pair (H(m), PK_i)
     // Final pair: (S_sum, -G2).
-    let mut input = vec![0u8; 192 * (k + 1)];
+    let mut pairs = Vec::with_capacity(k + 1);
+    for pk in signer_pubkeys {
+        pairs.push((h_g1, *pk));
+    }
+    pairs.push((*s_sum, G2Point(G2_MINUS_ONE)));
+
+    if pairing(&pairs)? {
+        Ok(())
+    } else {
+        Err(BLSError::BLSVerificationError)
+    }
+}
+
+/// Fast aggregate verify over public keys given in either encoding.
+/// Output:
+/// - Same as verify_fast_aggregate, after decompressing every PubKeyInput::Compressed key
+///
+/// Notes:
+/// - Same PoP requirement as verify_fast_aggregate.
+pub fn verify_fast_aggregate_mixed<M: AsRef<[u8]>>(
+    message: M,
+    signer_pubkeys: &[PubKeyInput],
+    s_sum: &G1Point,
+) -> Result<(), BLSError> {
+    let pubkeys = signer_pubkeys
+        .iter()
+        .map(G2Point::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    verify_fast_aggregate(message, &pubkeys, s_sum)
+}
+
+/// A precomputed sum of public keys, for a fixed signer set that verifies many messages.
+/// The same type as threshold::CommitteeKey; build it with CommitteeKey::from_keys.
+pub use crate::threshold::CommitteeKey as AggregatePubkey;
+
+/// Sum G2 partial signatures, for the min_pk flavor where signatures are in G2 and public
+/// keys in G1. Off-chain only, like all G2 addition.
+/// Output:
+/// - The sum, or Err(EmptySignerSet) for no partials
+#[cfg(not(target_os = "solana"))]
+pub fn aggregate_g2_partials(partials: &[G2Point]) -> Result<G2Point, BLSError> {
+    partials.iter().sum()
+}
 
-    for (i, pk) in signer_pubkeys.iter().enumerate() {
-        let off = 192 * i;
-        input[off..off + 64].copy_from_slice(&h_g1);
-        input[off + 64..off + 192].copy_from_slice(&pk.0);
+/// Fast aggregate verify for the min_pk flavor.
+/// Input:
+/// - message: message bytes
+/// - signer_g1_pubkeys: the signers' public keys sk_i * G1
+/// - s_sum: sum of the signers' G2 partials sk_i * H_G2(m), with H_G2 = hash_to_curve_g2 under
+///   MIN_PK_DST
+///
+/// Output:
+/// - Ok if e(PK_1, H_G2(m)) * ... * e(PK_k, H_G2(m)) * e(-G1, S_sum) == 1, Err otherwise
+///
+/// Notes:
+/// - Same PoP requirement as verify_fast_aggregate, and the same PointAtInfinity rejection of
+///   an identity s_sum or signer key.
+/// - Off-chain only, since hashing to G2 needs arkworks; the pairing itself is the usual syscall.
+#[cfg(not(target_os = "solana"))]
+pub fn verify_fast_aggregate_min_pk<M: AsRef<[u8]>>(
+    message: M,
+    signer_g1_pubkeys: &[G1Point],
+    s_sum: &G2Point,
+) -> Result<(), BLSError> {
+    let k = signer_g1_pubkeys.len();
+    if k == 0 {
+        return Err(BLSError::EmptySignerSet);
+    }
+    if !check_no_duplicate_keys(signer_g1_pubkeys.iter().map(|pk| &pk.0).collect()) {
+        return Err(BLSError::DuplicateSigner);
     }
+    if s_sum.is_identity() || signer_g1_pubkeys.iter().any(G1Point::is_identity) {
+        return Err(BLSError::PointAtInfinity);
+    }
+
+    let h_g2 = hash_to_curve_g2(message.as_ref(), MIN_PK_DST)?;
 
-    let off = 192 * k;
-    input[off..off + 64].copy_from_slice(&s_sum.0);
-    input[off + 64..off + 192].copy_from_slice(&G2_MINUS_ONE);
+    let mut pairs = Vec::with_capacity(k + 1);
+    for pk in signer_g1_pubkeys {
+        pairs.push((*pk, h_g2));
+    }
+    pairs.push((G1Point::neg_generator(), *s_sum));
 
-    let r = alt_bn128_pairing(&input).map_err(|_| BLSError::AltBN128PairingError)?;
-    let ok = r.iter().take(31).all(|&b| b == 0) && r[31] == 1;
-    if ok {
+    if pairing(&pairs)? {
         Ok(())
     } else {
         Err(BLSError::BLSVerificationError)
@@ -193,13 +348,18 @@ pub fn verify_fast_aggregate<M: AsRef<[u8]>>(
 /// - message: message bytes
 /// - signer_pubkeys: the exact G2 public keys that supposedly signed
 /// - s_sum: aggregated G1 signature = sum of augmented partial signatures
+///
 /// Output:
 /// - Ok if the aggregate verifies, Err otherwise
+///
 /// Notes:
 /// - This scheme binds each signer public key into the message hash. That prevents rogue-key
 ///   attacks without requiring PoP.
 /// - Each signer must have signed with bls_partial_sign_augmented.
 /// - Duplicate pubkeys are rejected to prevent counting the same signer more than once.
+/// - An identity s_sum or signer key is rejected with PointAtInfinity. Without a PoP nothing
+///   else stops the identity key from being registered, and it verifies the identity
+///   signature for every message.
 /// - This is slower than the fast path because it hashes once per signer.
 pub fn verify_augmented<M: AsRef<[u8]>>(
     message: M,
@@ -208,55 +368,442 @@ pub fn verify_augmented<M: AsRef<[u8]>>(
 ) -> Result<(), BLSError> {
     let k = signer_pubkeys.len();
     if k == 0 {
-        return Err(BLSError::SerializationError);
+        return Err(BLSError::EmptySignerSet);
     }
     if !check_no_duplicate_pubkeys(signer_pubkeys) {
-        return Err(BLSError::SerializationError);
+        return Err(BLSError::DuplicateSigner);
+    }
+    if s_sum.is_identity() || signer_pubkeys.iter().any(G2Point::is_identity) {
+        return Err(BLSError::PointAtInfinity);
     }
 
-    // Build input pairs
-    let mut input = vec![0u8; 192 * (k + 1)];
+    // Build pairing pairs
+    let mut pairs = Vec::with_capacity(k + 1);
 
     // For each signer: H(pk_i || message), pair with pk_i
     // Final pair: S_sum with -G2
-    for (i, pk) in signer_pubkeys.iter().enumerate() {
+    for pk in signer_pubkeys {
         let mut m = Vec::with_capacity(3 + 128 + message.as_ref().len());
         m.extend_from_slice(&pk.0);
         m.extend_from_slice(message.as_ref());
 
-        let h_g1 = hash_to_curve(&m)?.0;
+        pairs.push((hash_to_curve(&m)?, *pk));
+    }
+    pairs.push((*s_sum, G2Point(G2_MINUS_ONE)));
 
-        let off = 192 * i;
-        input[off..off + 64].copy_from_slice(&h_g1);
-        input[off + 64..off + 192].copy_from_slice(&pk.0);
+    // This is ~13k CU per pairing after an initial ~39k CU for the first one.
+    if pairing(&pairs)? {
+        Ok(())
+    } else {
+        Err(BLSError::BLSVerificationError)
     }
+}
 
-    let off = 192 * k;
-    input[off..off + 64].copy_from_slice(&s_sum.0);
-    input[off + 64..off + 192].copy_from_slice(&G2_MINUS_ONE);
+/// Batch verify independent (pk, message, signature) triples with one pairing.
+/// Input:
+/// - items: (PK_i, m_i, sig_i) for each signature
+///
+/// Output:
+/// - Ok if every signature verifies (with overwhelming probability), Err otherwise
+///
+/// Notes:
+/// - Checks prod e(r_i * H(m_i), PK_i) * e(sum r_i * sig_i, -G2) == 1 with a random 128-bit
+///   r_i per item, so a forger can't pick invalid signatures whose errors cancel out.
+/// - The r_i are derived with SHA-256 from every input (see batch_scalars), so the check is
+///   reproducible on-chain; since they depend on the signatures, they can't be known before
+///   the signatures are fixed.
+/// - Costs 2k G1 multiplications and k - 1 additions plus one pairing of k + 1 pairs, versus
+///   k two-pair pairings when verified one by one.
+/// - An identity key or signature in any item is rejected with PointAtInfinity.
+pub fn verify_batch(items: &[(G2Point, Vec<u8>, G1Point)]) -> Result<(), BLSError> {
+    if items.is_empty() {
+        return Err(BLSError::EmptyInput);
+    }
+    if items.iter().any(|(pk, _, sig)| pk.is_identity() || sig.is_identity()) {
+        return Err(BLSError::PointAtInfinity);
+    }
 
-    // This is ~13k CU per pairing after an initial ~39k CU for the first one.
-    let r = alt_bn128_pairing(&input).map_err(|_| BLSError::AltBN128PairingError)?;
-    let ok = r.iter().take(31).all(|&b| b == 0) && r[31] == 1;
-    if ok {
+    let scalars = batch_scalars(items);
+    let mut pairs = Vec::with_capacity(items.len() + 1);
+    let mut sig_sum: Option<G1Point> = None;
+    for ((pk, message, sig), r) in items.iter().zip(&scalars) {
+        pairs.push((g1_mul(hash_to_curve(message)?, r)?, *pk));
+        let weighted = g1_mul(*sig, r)?;
+        sig_sum = Some(match sig_sum {
+            None => weighted,
+            Some(acc) => g1_add(acc, weighted)?,
+        });
+    }
+    pairs.push((sig_sum.unwrap(), G2Point(G2_MINUS_ONE)));
+
+    if pairing(&pairs)? {
+        Ok(())
+    } else {
+        Err(BLSError::BLSVerificationError)
+    }
+}
+
+/// Batch coefficients for verify_batch:
+/// seed = sha256(for each item: PK_i || len(m_i) as 4 bytes BE || m_i || sig_i),
+/// r_i = the first 16 bytes of sha256(seed || i as 4 bytes BE), as a big-endian scalar.
+fn batch_scalars(items: &[(G2Point, Vec<u8>, G1Point)]) -> Vec<[u8; 32]> {
+    let mut transcript = Vec::new();
+    for (pk, message, sig) in items {
+        transcript.extend_from_slice(&pk.0);
+        transcript.extend_from_slice(&(message.len() as u32).to_be_bytes());
+        transcript.extend_from_slice(message);
+        transcript.extend_from_slice(&sig.0);
+    }
+    rlc_scalars(b"BLS-BN254-BATCH", &transcript, items.len())
+}
+
+/// n 128-bit random linear combination coefficients bound to a transcript:
+/// seed = sha256(domain || transcript), r_i = the first 16 bytes of
+/// sha256(seed || i as 4 bytes BE).
+pub(crate) fn rlc_scalars(domain: &[u8], transcript: &[u8], n: usize) -> Vec<[u8; 32]> {
+    let seed = solana_nostd_sha256::hashv(&[domain, transcript]);
+
+    (0..n as u32)
+        .map(|i| {
+            let h = solana_nostd_sha256::hashv(&[&seed, &i.to_be_bytes()]);
+            let mut r = [0u8; 32];
+            r[16..].copy_from_slice(&h[..16]);
+            r
+        })
+        .collect()
+}
+
+/// Aggregate verify where every signer signed a different message.
+/// Input:
+/// - pairs: (PK_i, m_i) for each signer
+/// - s_sum: aggregated G1 signature = sum of each signer's signature over its own message
+///
+/// Output:
+/// - Ok if e(H(m_1), PK_1) * ... * e(H(m_k), PK_k) * e(S_sum, -G2) == 1, Err otherwise
+///
+/// Notes:
+/// - The standard BLS aggregate, e.g. attestations over different blocks. No PoP is needed,
+///   but the messages must be distinct, so repeated messages are rejected.
+/// - Hashes every message; see verify_aggregate_distinct_precomputed when they're stable.
+/// - An identity s_sum or public key is rejected with PointAtInfinity.
+pub fn verify_aggregate_distinct(
+    pairs: &[(G2Point, Vec<u8>)],
+    s_sum: &G1Point,
+) -> Result<(), BLSError> {
+    let items = pairs
+        .iter()
+        .map(|(pk, m)| Ok((*pk, hash_to_curve(m)?)))
+        .collect::<Result<Vec<_>, BLSError>>()?;
+    verify_aggregate_distinct_precomputed(&items, s_sum)
+}
+
+/// Hash each message to G1 ahead of time for verify_aggregate_distinct_precomputed.
+/// Output:
+/// - H(m_i) for every message, in order
+pub fn precompute_hashes<M: AsRef<[u8]>>(messages: &[M]) -> Result<Vec<G1Point>, BLSError> {
+    messages.iter().map(|m| hash_to_curve(m.as_ref())).collect()
+}
+
+/// Aggregate verify where every signer signed a different message, with the message hashes
+/// already computed.
+/// Input:
+/// - items: (PK_i, H(m_i)) for each signer, with H(m_i) from precompute_hashes
+/// - s_sum: aggregated G1 signature = sum of each signer's signature over its own message
+///
+/// Output:
+/// - Ok if e(H(m_1), PK_1) * ... * e(H(m_k), PK_k) * e(S_sum, -G2) == 1, Err otherwise
+///
+/// Notes:
+/// - Skips hash_to_curve entirely, which is most of the cost when messages are stable.
+/// - Duplicate hashes are rejected with DuplicateMessage: this scheme is only secure when the
+///   messages are distinct.
+/// - The hashes are trusted as given, so they must come from precompute_hashes (or storage
+///   the program controls), never from the transaction.
+/// - An identity s_sum or public key is rejected with PointAtInfinity.
+pub fn verify_aggregate_distinct_precomputed(
+    items: &[(G2Point, G1Point)],
+    s_sum: &G1Point,
+) -> Result<(), BLSError> {
+    if items.is_empty() {
+        return Err(BLSError::EmptyInput);
+    }
+    if s_sum.is_identity() || items.iter().any(|(pk, _)| pk.is_identity()) {
+        return Err(BLSError::PointAtInfinity);
+    }
+    if !check_no_duplicate_keys(items.iter().map(|(_, h)| &h.0).collect()) {
+        return Err(BLSError::DuplicateMessage);
+    }
+
+    let mut pairs = Vec::with_capacity(items.len() + 1);
+    for (pk, h) in items {
+        pairs.push((*h, *pk));
+    }
+    pairs.push((*s_sum, G2Point(G2_MINUS_ONE)));
+
+    if pairing(&pairs)? {
         Ok(())
     } else {
         Err(BLSError::BLSVerificationError)
     }
 }
 
+/// Challenge message for verify_aggregate_wellformed.
+pub const WELLFORMED_CHALLENGE: &[u8] = b"BLS-BN254-WELLFORMED-CHALLENGE";
+
+/// Check that an aggregate signature and aggregate public key belong together, before any
+/// real message is chosen.
+/// Input:
+/// - agg_sig: aggregate of the members' signatures over WELLFORMED_CHALLENGE
+/// - agg_pk: aggregate of the same members' public keys
+///
+/// Output:
+/// - Ok if e(agg_sig, G2) == e(H, agg_pk), Err otherwise
+///
+/// Notes:
+/// - The challenge point is H = hash_to_curve(WELLFORMED_CHALLENGE), i.e. the ordinary message
+///   hash (tag "BLS-BN254-RO", try-and-increment) of the fixed challenge bytes. Members produce
+///   their part with bls_partial_sign(sk, WELLFORMED_CHALLENGE).
+/// - This is a setup-time consistency check, not a PoP: it doesn't stop rogue keys.
+pub fn verify_aggregate_wellformed(agg_sig: &G1Point, agg_pk: &G2Point) -> Result<(), BLSError> {
+    verify_fast_aggregate(WELLFORMED_CHALLENGE, &[*agg_pk], agg_sig)
+}
+
+/// Verify an aggregate signed by the whole committee except a few known non-signers.
+/// Input:
+/// - message: message bytes
+/// - full_agg_pk: sum of every committee public key (G2)
+/// - non_signers: public keys of the members that did not sign
+/// - s_sum: aggregated G1 signature of everyone else
+///
+/// Output:
+/// - Ok if s_sum verifies against effective_pk = full_agg_pk - sum(non_signers), Err otherwise
+///
+/// Notes:
+/// - On-chain there is no G2 addition syscall, so the subtraction is done inside the pairing:
+///   e(H(m), full_agg_pk) * prod e(H(m), -PK_j) * e(S_sum, -G2) == 1
+/// - This costs 2 + len(non_signers) pairings, a big saving when almost everyone signed.
+/// - Like verify_fast_aggregate, this is only safe if every public key has a PoP.
+/// - A repeated non-signer is rejected with DuplicateSigner, and an identity s_sum, full_agg_pk
+///   or non-signer key with PointAtInfinity.
+pub fn verify_all_except<M: AsRef<[u8]>>(
+    message: M,
+    full_agg_pk: &G2Point,
+    non_signers: &[G2Point],
+    s_sum: &G1Point,
+) -> Result<(), BLSError> {
+    if !check_no_duplicate_pubkeys(non_signers) {
+        return Err(BLSError::DuplicateSigner);
+    }
+    if s_sum.is_identity()
+        || full_agg_pk.is_identity()
+        || non_signers.iter().any(G2Point::is_identity)
+    {
+        return Err(BLSError::PointAtInfinity);
+    }
+
+    let h_g1 = hash_to_curve(message.as_ref())?;
+
+    let mut pairs = Vec::with_capacity(non_signers.len() + 2);
+    pairs.push((h_g1, *full_agg_pk));
+    for pk in non_signers {
+        pairs.push((h_g1, g2_neg(pk)));
+    }
+    pairs.push((*s_sum, G2Point(G2_MINUS_ONE)));
+
+    if pairing(&pairs)? {
+        Ok(())
+    } else {
+        Err(BLSError::BLSVerificationError)
+    }
+}
+
+/// Verify one signature with every input given as raw bytes.
+/// Input:
+/// - pubkey_g2_compressed: 64 byte compressed G2 public key
+/// - sig_g1_compressed: 32 byte compressed G1 signature
+/// - message: message bytes
+///
+/// Output:
+/// - Ok if the signature verifies, Err otherwise
+///
+/// Errors:
+/// - pubkey not 64 bytes: InvalidLength { expected: 64, got }, checked first
+/// - signature not 32 bytes: InvalidLength { expected: 32, got }
+/// - pubkey doesn't decompress: G2PointDecompressionError
+/// - signature doesn't decompress: G1PointDecompressionError
+/// - valid encodings, bad signature: BLSVerificationError
+pub fn verify_single(
+    pubkey_g2_compressed: &[u8],
+    sig_g1_compressed: &[u8],
+    message: &[u8],
+) -> Result<(), BLSError> {
+    let pk: [u8; 64] = pubkey_g2_compressed
+        .try_into()
+        .map_err(|_| BLSError::InvalidLength { expected: 64, got: pubkey_g2_compressed.len() })?;
+    let sig: [u8; 32] = sig_g1_compressed
+        .try_into()
+        .map_err(|_| BLSError::InvalidLength { expected: 32, got: sig_g1_compressed.len() })?;
+
+    let sig = G1Point::try_from(&G1CompressedPoint(sig))?;
+    G2CompressedPoint(pk).verify(&sig, message)
+}
+
+/// Find which one of a set of public keys produced a single signature.
+/// Input:
+/// - message: message bytes
+/// - pubkeys: candidate G2 public keys
+/// - signature: a single (non-aggregated) G1 signature
+///
+/// Output:
+/// - Some(i) for the first pubkeys[i] the signature verifies against, None if none match
+///
+/// Notes:
+/// - This is NOT a ring signature: the verifier learns exactly which key signed.
+/// - It costs one two-pair pairing per candidate tried.
+/// - An identity signature or candidate key is rejected with PointAtInfinity.
+pub fn verify_any_of<M: AsRef<[u8]>>(
+    message: M,
+    pubkeys: &[G2Point],
+    signature: &G1Point,
+) -> Result<Option<usize>, BLSError> {
+    if pubkeys.is_empty() {
+        return Err(BLSError::EmptyInput);
+    }
+    if signature.is_identity() || pubkeys.iter().any(G2Point::is_identity) {
+        return Err(BLSError::PointAtInfinity);
+    }
+
+    let h_g1 = hash_to_curve(message.as_ref())?;
+    for (i, pk) in pubkeys.iter().enumerate() {
+        if pairing(&[(h_g1, *pk), (*signature, G2Point(G2_MINUS_ONE))])? {
+            return Ok(Some(i));
+        }
+    }
+    Ok(None)
+}
+
+/// Hash a Merkle leaf: sha256(0x00 || leaf).
+pub fn merkle_leaf_hash(leaf: &[u8]) -> [u8; 32] {
+    solana_nostd_sha256::hashv(&[&[0x00], leaf])
+}
+
+/// Hash two Merkle nodes: sha256(0x01 || min(a, b) || max(a, b)).
+/// The pair is sorted so proofs don't need left/right position bits.
+pub fn merkle_node_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (l, r) = if a <= b { (a, b) } else { (b, a) };
+    solana_nostd_sha256::hashv(&[&[0x01], l, r])
+}
+
+/// Verify that `leaf` was signed as part of a Merkle-committed batch.
+/// Input:
+/// - root: Merkle root the committee signed
+/// - leaf: leaf bytes
+/// - proof: sibling hashes from the leaf up to the root
+/// - signer_pubkeys: the exact G2 public keys that supposedly signed the root
+/// - s_sum: aggregated G1 signature over the root
+///
+/// Output:
+/// - Ok if the leaf is in the tree and the aggregate over the root verifies, Err otherwise
+///
+/// Notes:
+/// - Leaves and nodes are hashed with merkle_leaf_hash / merkle_node_hash (domain separated,
+///   sorted pairs).
+/// - The signature check is verify_fast_aggregate over the 32 root bytes, so the PoP
+///   requirement applies.
+pub fn verify_merkle_leaf(
+    root: &[u8; 32],
+    leaf: &[u8],
+    proof: &[[u8; 32]],
+    signer_pubkeys: &[G2Point],
+    s_sum: &G1Point,
+) -> Result<(), BLSError> {
+    let computed = proof
+        .iter()
+        .fold(merkle_leaf_hash(leaf), |acc, sibling| merkle_node_hash(&acc, sibling));
+    if computed != *root {
+        return Err(BLSError::BLSVerificationError);
+    }
+
+    verify_fast_aggregate(root, signer_pubkeys, s_sum)
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
+        aggregate_g2_partials,
         aggregate_partials,
+        aggregate_partials_iter,
+        check_no_duplicate_pubkeys,
         bls_partial_sign,
         bls_partial_sign_augmented,
+        bls_partial_sign_with_dst,
+        bls_partial_sign_with_hasher,
+        merkle_leaf_hash,
+        merkle_node_hash,
+        precompute_hashes,
+        verify_aggregate_distinct,
+        verify_aggregate_distinct_precomputed,
+        verify_aggregate_wellformed,
+        WELLFORMED_CHALLENGE,
+        verify_all_except,
+        verify_any_of,
+        verify_batch,
         verify_fast_aggregate,
+        verify_fast_aggregate_min_pk,
+        verify_fast_aggregate_mixed,
+        verify_fast_aggregate_with_dst,
+        verify_fast_aggregate_with_hasher,
         verify_augmented,
+        verify_merkle_leaf,
+        verify_single,
     };
-    use crate::g1::G1Point;
-    use crate::g2::G2Point;
+    use crate::errors::BLSError;
+    use crate::g1::{G1CompressedPoint, G1Point};
+    use crate::g2::{G2CompressedPoint, G2Point, PubKeyInput};
+    use crate::hash::hash_to_curve;
     use crate::privkey::PrivKey;
+    use crate::scalar::Fr;
+    use ark_bn254::{Bn254, G1Affine, G2Affine};
+    use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+    use ark_serialize::CanonicalDeserialize;
+
+    fn g1_to_ark(p: &G1Point) -> G1Affine {
+        let mut bytes = p.0;
+        bytes[..32].reverse();
+        bytes[32..].reverse();
+        G1Affine::deserialize_uncompressed(&bytes[..]).expect("g1 to ark")
+    }
+
+    fn g2_to_ark(p: &G2Point) -> G2Affine {
+        let mut bytes = G2CompressedPoint::try_from(p).expect("compress").0;
+        bytes.reverse();
+        G2Affine::deserialize_compressed(&bytes[..]).expect("g2 to ark")
+    }
+
+    /// Differential check: run verify_fast_aggregate and an independent arkworks pairing check
+    /// e(H(m), sum PK_i) == e(S_sum, G2) on the same inputs, and assert that they agree.
+    fn cross_check_aggregate(
+        message: &[u8],
+        pubkeys: &[G2Point],
+        s_sum: &G1Point,
+    ) -> Result<bool, BLSError> {
+        let fast = match verify_fast_aggregate(message, pubkeys, s_sum) {
+            Ok(()) => true,
+            Err(BLSError::BLSVerificationError) => false,
+            Err(e) => return Err(e),
+        };
+
+        let pk_agg = pubkeys
+            .iter()
+            .fold(G2Affine::zero().into_group(), |acc, pk| acc + g2_to_ark(pk))
+            .into_affine();
+        let h = g1_to_ark(&hash_to_curve(message)?);
+        let full =
+            Bn254::pairing(h, pk_agg) == Bn254::pairing(g1_to_ark(s_sum), G2Affine::generator());
+
+        assert_eq!(fast, full, "fast aggregate and full pairing check disagree");
+        Ok(fast)
+    }
 
     #[test]
     fn fast_aggregate_random() {
@@ -267,12 +814,62 @@ mod tests {
         let pks: Vec<G2Point> = keys.iter().map(|k| G2Point::try_from(k).unwrap()).collect();
 
         let partials: Vec<G1Point> = keys.iter()
-            .map(|k| bls_partial_sign(&k.0, msg).unwrap())
+            .map(|k| bls_partial_sign(&Fr::try_from(k).unwrap(), msg).unwrap())
             .collect();
 
         let s_sum = aggregate_partials(&partials).expect("aggregate");
 
         verify_fast_aggregate(msg, &pks, &s_sum).expect("fast agg verify");
+        assert!(cross_check_aggregate(msg, &pks, &s_sum).expect("cross check"));
+        assert!(!cross_check_aggregate(b"other", &pks, &s_sum).expect("cross check"));
+        assert!(!cross_check_aggregate(msg, &pks[1..], &s_sum).expect("cross check"));
+    }
+
+    #[test]
+    fn fast_aggregate_min_pk_symmetric() {
+        use crate::hash::{hash_to_curve_g2, MIN_PK_DST};
+
+        let msg = b"fast-agg-min-pk";
+        let keys: Vec<PrivKey> = (0..8).map(|_| PrivKey::from_random()).collect();
+        let pks: Vec<G1Point> =
+            keys.iter().map(|k| G1Point::try_from(PrivKey(k.0)).unwrap()).collect();
+
+        let h = hash_to_curve_g2(msg, MIN_PK_DST).expect("hash");
+        let partials: Vec<G2Point> = keys.iter().map(|k| h.scalar_mul(&k.0).unwrap()).collect();
+        let s_sum = aggregate_g2_partials(&partials).expect("aggregate");
+
+        verify_fast_aggregate_min_pk(msg, &pks, &s_sum).expect("min_pk verify");
+        let err = verify_fast_aggregate_min_pk(b"other", &pks, &s_sum).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+        let err = verify_fast_aggregate_min_pk(msg, &pks[1..], &s_sum).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+        assert!(verify_fast_aggregate_min_pk(msg, &[pks[0], pks[0]], &s_sum).is_err());
+        assert_eq!(aggregate_g2_partials(&[]).unwrap_err(), BLSError::EmptySignerSet);
+    }
+
+    #[test]
+    fn fast_aggregate_min_pk_rejects_identity() {
+        let pk = G1Point::try_from(PrivKey::from_random()).unwrap();
+        let sig = G2Point::generator();
+        let err = verify_fast_aggregate_min_pk(b"m", &[pk], &G2Point([0u8; 128])).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        let err = verify_fast_aggregate_min_pk(b"m", &[pk, G1Point::IDENTITY], &sig).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+    }
+
+    #[test]
+    fn aggregate_partials_iter_matches_slice() {
+        let msg = b"agg-iter";
+        let keys: Vec<Fr> = (0..5).map(|_| Fr::random()).collect();
+
+        let lazy = (0..keys.len()).map(|i| bls_partial_sign(&keys[i], msg).unwrap());
+        let from_iter = aggregate_partials_iter(lazy).expect("iter");
+        let partials: Vec<G1Point> =
+            keys.iter().map(|k| bls_partial_sign(k, msg).unwrap()).collect();
+        assert_eq!(from_iter, aggregate_partials(&partials).expect("slice"));
+
+        let err = aggregate_partials_iter(core::iter::empty()).unwrap_err();
+        assert_eq!(err, BLSError::EmptySignerSet);
     }
 
     #[test]
@@ -284,7 +881,7 @@ mod tests {
         let pks: Vec<G2Point> = keys.iter().map(|k| G2Point::try_from(k).unwrap()).collect();
 
         let partials: Vec<G1Point> = keys.iter()
-            .map(|k| bls_partial_sign(&k.0, m1).unwrap())
+            .map(|k| bls_partial_sign(&Fr::try_from(k).unwrap(), m1).unwrap())
             .collect();
 
         let s_sum = aggregate_partials(&partials).expect("aggregate");
@@ -300,12 +897,24 @@ mod tests {
         let pk = G2Point::try_from(&sk).unwrap();
 
         // Create a valid partial signature
-        let s = bls_partial_sign(&sk.0, msg).unwrap();
-        let s_sum = aggregate_partials(&[s.clone(), s]).unwrap();
+        let s = bls_partial_sign(&Fr::try_from(&sk).unwrap(), msg).unwrap();
+        let s_sum = aggregate_partials(&[s, s]).unwrap();
 
         // Duplicate pks must be rejected
         let err = verify_fast_aggregate(msg, &[pk, pk], &s_sum).unwrap_err();
-        assert_eq!(err, crate::errors::BLSError::SerializationError);
+        assert_eq!(err, crate::errors::BLSError::DuplicateSigner);
+
+        // So must an empty signer set and the identity key
+        let err = verify_fast_aggregate(msg, &[], &s_sum).unwrap_err();
+        assert_eq!(err, crate::errors::BLSError::EmptySignerSet);
+        let err = verify_fast_aggregate(msg, &[pk, G2Point([0u8; 128])], &s_sum).unwrap_err();
+        assert_eq!(err, crate::errors::BLSError::PointAtInfinity);
+
+        // A legitimate signature swapped for the identity
+        let single = bls_partial_sign(&Fr::try_from(&sk).unwrap(), msg).unwrap();
+        verify_fast_aggregate(msg, &[pk], &single).expect("verify");
+        let err = verify_fast_aggregate(msg, &[pk], &G1Point::IDENTITY).unwrap_err();
+        assert_eq!(err, crate::errors::BLSError::PointAtInfinity);
     }
 
     #[test]
@@ -318,7 +927,7 @@ mod tests {
 
         let partials: Vec<G1Point> = keys.iter()
             .zip(pks.iter())
-            .map(|(k, pk)| bls_partial_sign_augmented(&k.0, msg, pk).unwrap())
+            .map(|(k, pk)| bls_partial_sign_augmented(&Fr::try_from(k).unwrap(), msg, pk).unwrap())
             .collect();
 
         let s_sum = aggregate_partials(&partials).expect("aggregate");
@@ -333,10 +942,452 @@ mod tests {
         let sk = PrivKey::from_random();
         let pk = G2Point::try_from(&sk).unwrap();
 
-        let s = bls_partial_sign_augmented(&sk.0, m1, &pk).unwrap();
+        let s = bls_partial_sign_augmented(&Fr::try_from(&sk).unwrap(), m1, &pk).unwrap();
         let s_sum = aggregate_partials(&[s]).unwrap();
 
         let err = verify_augmented(m2, &[pk], &s_sum).unwrap_err();
         assert_eq!(err, crate::errors::BLSError::BLSVerificationError);
     }
+
+    #[test]
+    fn augmented_rejects_identity() {
+        // e(H(0 || m), 0) * e(0, -G2) == 1 for every m, and nothing else keeps the identity key
+        // out of a PoP-free committee
+        let identity_pk = G2Point([0u8; 128]);
+        let err = verify_augmented(b"any", &[identity_pk], &G1Point::IDENTITY).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+
+        let sk = PrivKey::from_random();
+        let pk = G2Point::try_from(&sk).unwrap();
+        let s = bls_partial_sign_augmented(&Fr::try_from(&sk).unwrap(), b"m", &pk).unwrap();
+        let err = verify_augmented(b"m", &[pk, identity_pk], &s).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        let err = verify_augmented(b"m", &[pk], &G1Point::IDENTITY).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        verify_augmented(b"m", &[pk], &s).expect("verify");
+    }
+
+    #[test]
+    fn distinct_and_any_of_reject_identity() {
+        let sk = PrivKey::from_random();
+        let pk = G2Point::try_from(&sk).unwrap();
+        let sig = sk.sign(b"m").unwrap();
+        let identity_pk = G2Point([0u8; 128]);
+
+        let pairs = vec![(pk, b"m".to_vec()), (identity_pk, b"other".to_vec())];
+        let err = verify_aggregate_distinct(&pairs, &sig).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        let pairs = vec![(pk, b"m".to_vec())];
+        let err = verify_aggregate_distinct(&pairs, &G1Point::IDENTITY).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        verify_aggregate_distinct(&pairs, &sig).expect("verify");
+
+        let err = verify_any_of(b"m", &[identity_pk, pk], &sig).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        let err = verify_any_of(b"m", &[pk], &G1Point::IDENTITY).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        assert_eq!(verify_any_of(b"m", &[pk], &sig).unwrap(), Some(0));
+    }
+
+    #[test]
+    fn all_except_matches_explicit_signers() {
+        let msg = b"all-except";
+
+        let keys: Vec<PrivKey> = (0..6).map(|_| PrivKey::from_random()).collect();
+        let pks: Vec<G2Point> = keys.iter().map(|k| G2Point::try_from(k).unwrap()).collect();
+        let full_agg_pk = pks[1..].iter().fold(pks[0], |acc, pk| acc + *pk);
+
+        // Everyone except members 1 and 4 signed
+        let signers = [0usize, 2, 3, 5];
+        let partials: Vec<G1Point> = signers.iter()
+            .map(|&i| bls_partial_sign(&Fr::try_from(&keys[i]).unwrap(), msg).unwrap())
+            .collect();
+        let s_sum = aggregate_partials(&partials).expect("aggregate");
+
+        let signer_pks: Vec<G2Point> = signers.iter().map(|&i| pks[i]).collect();
+        verify_fast_aggregate(msg, &signer_pks, &s_sum).expect("explicit verify");
+        verify_all_except(msg, &full_agg_pk, &[pks[1], pks[4]], &s_sum).expect("all except");
+
+        // Claiming the wrong non-signer set must fail
+        let err = verify_all_except(msg, &full_agg_pk, &[pks[1]], &s_sum).unwrap_err();
+        assert_eq!(err, crate::errors::BLSError::BLSVerificationError);
+    }
+
+    #[test]
+    fn all_except_rejects_identity() {
+        let sk = PrivKey::from_random();
+        let pk = G2Point::try_from(&sk).unwrap();
+        let sig = sk.sign(b"m").unwrap();
+        let identity_pk = G2Point([0u8; 128]);
+
+        let err = verify_all_except(b"m", &pk, &[], &G1Point::IDENTITY).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        let err = verify_all_except(b"m", &identity_pk, &[], &sig).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        let err = verify_all_except(b"m", &pk, &[identity_pk], &sig).unwrap_err();
+        assert_eq!(err, BLSError::PointAtInfinity);
+        verify_all_except(b"m", &pk, &[], &sig).expect("verify");
+
+        let err = verify_all_except(b"m", &pk, &[pk, pk], &sig).unwrap_err();
+        assert_eq!(err, BLSError::DuplicateSigner);
+    }
+
+    #[test]
+    fn merkle_leaf_verifies_against_signed_root() {
+        let leaves: [&[u8]; 4] = [b"leaf-0", b"leaf-1", b"leaf-2", b"leaf-3"];
+        let h: Vec<[u8; 32]> = leaves.iter().map(|l| merkle_leaf_hash(l)).collect();
+        let n01 = merkle_node_hash(&h[0], &h[1]);
+        let n23 = merkle_node_hash(&h[2], &h[3]);
+        let root = merkle_node_hash(&n01, &n23);
+
+        let keys: Vec<PrivKey> = (0..3).map(|_| PrivKey::from_random()).collect();
+        let pks: Vec<G2Point> = keys.iter().map(|k| G2Point::try_from(k).unwrap()).collect();
+        let partials: Vec<G1Point> = keys.iter()
+            .map(|k| bls_partial_sign(&Fr::try_from(k).unwrap(), root).unwrap())
+            .collect();
+        let s_sum = aggregate_partials(&partials).expect("aggregate");
+
+        // Leaf 2 is proven by its sibling leaf 3 and the opposite subtree
+        verify_merkle_leaf(&root, leaves[2], &[h[3], n01], &pks, &s_sum).expect("leaf verify");
+
+        // A forged leaf with the same proof must be rejected
+        let err = verify_merkle_leaf(&root, b"forged", &[h[3], n01], &pks, &s_sum).unwrap_err();
+        assert_eq!(err, crate::errors::BLSError::BLSVerificationError);
+    }
+
+    #[test]
+    fn aggregate_matches_sequential_sum() {
+        for k in [1usize, 2, 3, 16, 64] {
+            let partials: Vec<G1Point> = (0..k)
+                .map(|_| G1Point::try_from(PrivKey::from_random()).unwrap())
+                .collect();
+
+            let sequential = partials[1..]
+                .iter()
+                .fold(partials[0], |acc, p| crate::bn254::g1_add(acc, *p).unwrap());
+            let batched = aggregate_partials(&partials).expect("aggregate");
+            assert_eq!(batched.0, sequential.0, "mismatch for k = {k}");
+        }
+
+        // A partial and its negation sum to the identity (all-zero bytes)
+        let p = G1Point::try_from(PrivKey::from_random()).unwrap();
+        let mut neg = p.0;
+        neg[32..].copy_from_slice(&crate::bn254::fq_neg(&p.0[32..]));
+        assert_eq!(aggregate_partials(&[p, G1Point(neg)]).unwrap().0, [0u8; 64]);
+    }
+
+    #[test]
+    fn any_of_identifies_signer() {
+        let msg = b"any-of";
+
+        let keys: Vec<PrivKey> = (0..5).map(|_| PrivKey::from_random()).collect();
+        let pks: Vec<G2Point> = keys.iter().map(|k| G2Point::try_from(k).unwrap()).collect();
+
+        let sig = keys[3].sign(msg).expect("sign");
+        assert_eq!(verify_any_of(msg, &pks, &sig).expect("any of"), Some(3));
+
+        // A signer outside the set is not found
+        let outsider = PrivKey::from_random().sign(msg).expect("sign");
+        assert_eq!(verify_any_of(msg, &pks, &outsider).expect("any of"), None);
+    }
+
+    #[test]
+    fn distinct_precomputed_matches_raw_messages() {
+        let keys: Vec<PrivKey> = (0..3).map(|_| PrivKey::from_random()).collect();
+        let pks: Vec<G2Point> = keys.iter().map(|k| G2Point::try_from(k).unwrap()).collect();
+        let messages: [&[u8]; 3] = [b"distinct-0", b"distinct-1", b"distinct-2"];
+
+        // Each signer signs its own message; the raw path hashes each message on the spot
+        let sigs: Vec<G1Point> = keys
+            .iter()
+            .zip(messages)
+            .map(|(k, m)| bls_partial_sign(&Fr::try_from(k).unwrap(), m).unwrap())
+            .collect();
+        let s_sum = aggregate_partials(&sigs).unwrap();
+        let raw: Vec<(G2Point, G1Point)> = pks
+            .iter()
+            .zip(messages)
+            .map(|(pk, m)| (*pk, hash_to_curve(m).unwrap()))
+            .collect();
+
+        let hashes = precompute_hashes(&messages).expect("precompute");
+        let items: Vec<(G2Point, G1Point)> = pks.iter().copied().zip(hashes).collect();
+        for (a, b) in raw.iter().zip(&items) {
+            assert_eq!(a.1 .0, b.1 .0);
+        }
+        verify_aggregate_distinct_precomputed(&items, &s_sum).expect("precomputed verify");
+
+        // Pairing a key with someone else's hash fails, and repeated hashes are rejected
+        let mut swapped = items.clone();
+        swapped[0].1 = items[1].1;
+        swapped[1].1 = items[0].1;
+        let err = verify_aggregate_distinct_precomputed(&swapped, &s_sum).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+
+        let mut dup = items;
+        dup[2].1 = dup[0].1;
+        let err = verify_aggregate_distinct_precomputed(&dup, &s_sum).unwrap_err();
+        assert_eq!(err, BLSError::DuplicateMessage);
+    }
+
+    #[test]
+    fn partial_sign_rejects_out_of_range_scalars() {
+        use crate::consts::SCALAR_ORDER;
+
+        let msg = b"scalar-range";
+        let mut r_minus_one = SCALAR_ORDER;
+        r_minus_one[31] -= 1;
+
+        assert_eq!(bls_partial_sign(&Fr::zero(), msg).unwrap_err(), BLSError::ScalarOutOfRange);
+        let err = bls_partial_sign(&Fr(SCALAR_ORDER), msg).unwrap_err();
+        assert_eq!(err, BLSError::ScalarOutOfRange);
+        let err = bls_partial_sign_augmented(&Fr(SCALAR_ORDER), msg, &G2Point::generator());
+        assert_eq!(err.unwrap_err(), BLSError::ScalarOutOfRange);
+
+        // r - 1 = -1 signs to -H(m)
+        let s = bls_partial_sign(&Fr(r_minus_one), msg).unwrap();
+        assert!(s == -hash_to_curve(msg).unwrap());
+    }
+
+    #[test]
+    fn fast_aggregate_with_custom_dst() {
+        let msg = b"custom-dst";
+        let dst = b"APP-V1-VOTE";
+        let keys: Vec<PrivKey> = (0..3).map(|_| PrivKey::from_random()).collect();
+        let pks: Vec<G2Point> = keys.iter().map(|k| G2Point::try_from(k).unwrap()).collect();
+        let partials: Vec<G1Point> = keys
+            .iter()
+            .map(|k| bls_partial_sign_with_dst(&Fr::try_from(k).unwrap(), msg, dst).unwrap())
+            .collect();
+        let s_sum = aggregate_partials(&partials).unwrap();
+
+        verify_fast_aggregate_with_dst(msg, &pks, &s_sum, dst).expect("same dst");
+        assert!(verify_fast_aggregate_with_dst(msg, &pks, &s_sum, b"APP-V1-OTHER").is_err());
+        assert!(verify_fast_aggregate(msg, &pks, &s_sum).is_err());
+    }
+
+    #[test]
+    fn fast_aggregate_with_pluggable_hasher() {
+        use crate::hash::{hash_to_curve_rfc9380, HashToCurve, Sha256, DEFAULT_DST};
+
+        struct Rfc9380;
+        impl HashToCurve for Rfc9380 {
+            fn try_hash_to_curve(message: &[u8], dst: &[u8]) -> Result<G1Point, BLSError> {
+                hash_to_curve_rfc9380(message, dst)
+            }
+        }
+
+        let msg = b"pluggable-hasher";
+        let keys: Vec<PrivKey> = (0..3).map(|_| PrivKey::from_random()).collect();
+        let pks: Vec<G2Point> = keys.iter().map(|k| G2Point::try_from(k).unwrap()).collect();
+        let sign = |k: &PrivKey| Fr::try_from(k).unwrap();
+
+        // The SHA-256 marker is the default path
+        let sha: Vec<G1Point> = keys
+            .iter()
+            .map(|k| bls_partial_sign_with_hasher::<Sha256>(&sign(k), msg, DEFAULT_DST).unwrap())
+            .collect();
+        assert!(sha[0] == bls_partial_sign(&sign(&keys[0]), msg).unwrap());
+        let sha_sum = aggregate_partials(&sha).unwrap();
+        verify_fast_aggregate(msg, &pks, &sha_sum).expect("default verify");
+
+        let rfc: Vec<G1Point> = keys
+            .iter()
+            .map(|k| bls_partial_sign_with_hasher::<Rfc9380>(&sign(k), msg, DEFAULT_DST).unwrap())
+            .collect();
+        let rfc_sum = aggregate_partials(&rfc).unwrap();
+        verify_fast_aggregate_with_hasher::<Rfc9380, _>(msg, &pks, &rfc_sum, DEFAULT_DST)
+            .expect("rfc9380 verify");
+        assert!(verify_fast_aggregate(msg, &pks, &rfc_sum).is_err());
+    }
+
+    #[cfg(feature = "keccak")]
+    #[test]
+    fn fast_aggregate_keccak_roundtrip_and_cross_hash() {
+        use super::{bls_partial_sign_keccak, verify_fast_aggregate_keccak};
+
+        let msg = b"evm-interop";
+        let dst = b"BLS-BN254-RO";
+        let keys: Vec<PrivKey> = (0..3).map(|_| PrivKey::from_random()).collect();
+        let pks: Vec<G2Point> = keys.iter().map(|k| G2Point::try_from(k).unwrap()).collect();
+        let sign = |keccak: bool| -> G1Point {
+            let partials: Vec<G1Point> = keys
+                .iter()
+                .map(|k| {
+                    let sk = Fr::try_from(k).unwrap();
+                    if keccak {
+                        bls_partial_sign_keccak(&sk, msg, dst).unwrap()
+                    } else {
+                        bls_partial_sign_with_dst(&sk, msg, dst).unwrap()
+                    }
+                })
+                .collect();
+            aggregate_partials(&partials).unwrap()
+        };
+
+        let keccak_sig = sign(true);
+        let sha_sig = sign(false);
+        verify_fast_aggregate_keccak(msg, &pks, &keccak_sig, dst).expect("keccak");
+        verify_fast_aggregate_with_dst(msg, &pks, &sha_sig, dst).expect("sha256");
+        assert!(verify_fast_aggregate_keccak(msg, &pks, &sha_sig, dst).is_err());
+        assert!(verify_fast_aggregate_with_dst(msg, &pks, &keccak_sig, dst).is_err());
+    }
+
+    #[test]
+    fn fast_aggregate_mixed_encodings() {
+        let msg = b"mixed-encodings";
+        let keys: Vec<PrivKey> = (0..4).map(|_| PrivKey::from_random()).collect();
+        let partials: Vec<G1Point> = keys
+            .iter()
+            .map(|k| bls_partial_sign(&Fr::try_from(k).unwrap(), msg).unwrap())
+            .collect();
+        let s_sum = aggregate_partials(&partials).unwrap();
+
+        let inputs: Vec<PubKeyInput> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, k)| {
+                if i % 2 == 0 {
+                    PubKeyInput::Compressed(G2CompressedPoint::try_from(k).unwrap())
+                } else {
+                    PubKeyInput::Uncompressed(G2Point::try_from(k).unwrap())
+                }
+            })
+            .collect();
+        verify_fast_aggregate_mixed(msg, &inputs, &s_sum).expect("mixed verify");
+
+        // Dropping a signer fails, and a compressed key that doesn't decompress is an error
+        assert!(verify_fast_aggregate_mixed(msg, &inputs[1..], &s_sum).is_err());
+        let mut bad = inputs.clone();
+        bad[0] = PubKeyInput::Compressed(G2CompressedPoint([0xff; 64]));
+        let err = verify_fast_aggregate_mixed(msg, &bad, &s_sum).unwrap_err();
+        assert_eq!(err, BLSError::G2PointDecompressionError);
+    }
+
+    #[test]
+    fn aggregate_wellformed_consistent_and_not() {
+        let keys: Vec<PrivKey> = (0..3).map(|_| PrivKey::from_random()).collect();
+        let pks: Vec<G2Point> = keys.iter().map(|k| G2Point::try_from(k).unwrap()).collect();
+        let agg_pk = pks[0] + pks[1] + pks[2];
+        let partials: Vec<G1Point> = keys
+            .iter()
+            .map(|k| Fr::try_from(k).unwrap())
+            .map(|sk| bls_partial_sign(&sk, WELLFORMED_CHALLENGE).unwrap())
+            .collect();
+        let agg_sig = aggregate_partials(&partials).unwrap();
+
+        verify_aggregate_wellformed(&agg_sig, &agg_pk).expect("wellformed");
+
+        // A signature missing one member doesn't match the full aggregate key
+        let short = aggregate_partials(&partials[..2]).unwrap();
+        let err = verify_aggregate_wellformed(&short, &agg_pk).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+    }
+
+    #[test]
+    fn verify_single_reports_each_bad_field() {
+        let sk = PrivKey::from_random();
+        let msg = b"verify-single";
+        let pk = G2CompressedPoint::try_from(&sk).unwrap().0;
+        let sig = G1CompressedPoint::try_from(sk.sign(msg).unwrap()).unwrap().0;
+
+        verify_single(&pk, &sig, msg).expect("verify");
+        let err = verify_single(&pk, &sig, b"other").unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+
+        // Every wrong-length combination, including empty and swapped inputs
+        let len = |expected, got| BLSError::InvalidLength { expected, got };
+        let cases: [(&[u8], &[u8], BLSError); 6] = [
+            (&pk[..63], &sig, len(64, 63)),
+            (&[], &sig, len(64, 0)),
+            (&pk, &sig[..31], len(32, 31)),
+            (&pk, &[0u8; 33], len(32, 33)),
+            (&pk[..63], &sig[..31], len(64, 63)),
+            (&sig, &pk, len(64, 32)),
+        ];
+        for (p, s, expected) in cases {
+            assert_eq!(verify_single(p, s, msg).unwrap_err(), expected);
+        }
+
+        // Right lengths, bytes that don't decompress
+        let err = verify_single(&[0xffu8; 64], &sig, msg).unwrap_err();
+        assert_eq!(err, BLSError::G2PointDecompressionError);
+        let err = verify_single(&pk, &[0xffu8; 32], msg).unwrap_err();
+        assert_eq!(err, BLSError::G1PointDecompressionError);
+    }
+
+    #[test]
+    fn duplicate_pubkeys_detected_among_100() {
+        // The check is purely on bytes, so distinct synthetic keys are enough
+        let mut pks: Vec<G2Point> = (0..100u32)
+            .map(|i| {
+                let mut b = [0u8; 128];
+                b[..4].copy_from_slice(&i.to_be_bytes());
+                b[124..].copy_from_slice(&(i * 7).to_be_bytes());
+                G2Point(b)
+            })
+            .collect();
+        assert!(check_no_duplicate_pubkeys(&pks));
+
+        pks[99] = pks[98];
+        assert!(!check_no_duplicate_pubkeys(&pks));
+        assert!(check_no_duplicate_pubkeys(&[]));
+    }
+
+    #[test]
+    fn batch_of_five_and_each_corruption() {
+        let items: Vec<(G2Point, Vec<u8>, G1Point)> = (0..5u8)
+            .map(|i| {
+                let sk = PrivKey::from_random();
+                let msg = vec![b'b', i];
+                let sig = sk.sign(&msg).unwrap();
+                (G2Point::try_from(&sk).unwrap(), msg, sig)
+            })
+            .collect();
+        verify_batch(&items).expect("batch");
+
+        // Replacing any one signature with a signature over another message breaks the batch
+        for i in 0..items.len() {
+            let mut bad = items.clone();
+            bad[i].2 = bad[(i + 1) % 5].2;
+            assert_eq!(verify_batch(&bad).unwrap_err(), BLSError::BLSVerificationError);
+        }
+        assert!(verify_batch(&[]).is_err());
+    }
+
+    #[test]
+    fn batch_rejects_identity() {
+        let sk = PrivKey::from_random();
+        let item = (G2Point::try_from(&sk).unwrap(), b"m".to_vec(), sk.sign(b"m").unwrap());
+
+        let mut bad = vec![item.clone(), item.clone()];
+        bad[1].0 = G2Point([0u8; 128]);
+        bad[1].2 = G1Point::IDENTITY;
+        assert_eq!(verify_batch(&bad).unwrap_err(), BLSError::PointAtInfinity);
+        bad[1] = (item.0, b"m".to_vec(), G1Point::IDENTITY);
+        assert_eq!(verify_batch(&bad).unwrap_err(), BLSError::PointAtInfinity);
+        verify_batch(&[item]).expect("batch");
+    }
+
+    #[test]
+    fn aggregate_distinct_messages() {
+        let keys: Vec<PrivKey> = (0..3).map(|_| PrivKey::from_random()).collect();
+        let pairs: Vec<(G2Point, Vec<u8>)> = keys
+            .iter()
+            .enumerate()
+            .map(|(i, k)| (G2Point::try_from(k).unwrap(), format!("block-{}", i).into_bytes()))
+            .collect();
+        let sigs: Vec<G1Point> =
+            keys.iter().zip(&pairs).map(|(k, (_, m))| k.sign(m).unwrap()).collect();
+        let s_sum = aggregate_partials(&sigs).unwrap();
+
+        verify_aggregate_distinct(&pairs, &s_sum).expect("distinct verify");
+
+        // Swapping two signers' messages breaks the aggregate
+        let mut swapped = pairs.clone();
+        swapped[0].1 = pairs[1].1.clone();
+        swapped[1].1 = pairs[0].1.clone();
+        let err = verify_aggregate_distinct(&swapped, &s_sum).unwrap_err();
+        assert_eq!(err, BLSError::BLSVerificationError);
+    }
 }
@@ -1,8 +1,14 @@
-// Minimal Solana program example using brine-bn128-bls that verifies a single BLS signature.
-// Instruction layout: [pk_c: 64 bytes | sig_c: 32 bytes | msg: remaining bytes]
+// Minimal Solana program example using brine-bn128-bls. The first instruction byte selects:
+// 0: verify a single BLS signature.
+//    Instruction layout: [0 | pk_c: 64 bytes | sig_c: 32 bytes | msg: remaining bytes]
+// 1: aggregate partials from account data and A1-verify them in one call
+//    (threshold::verify_from_partials_blob).
+//    Accounts: [committee registry (64 byte compressed keys), partials blob (32 bytes each)]
+//    Instruction layout: [1 | k: u16 LE | k signer indices: u16 LE each | msg: remaining bytes]
 
 #![allow(unexpected_cfgs)]
 
+use brine_bn128_bls::threshold::{verify_from_partials_blob, Registry};
 use brine_bn128_bls::{G1CompressedPoint, G1Point, G2CompressedPoint};
 use pinocchio::{
     account_info::AccountInfo, entrypoint, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
@@ -15,29 +21,62 @@ entrypoint!(process_instruction);
 
 fn process_instruction(
     _program_id: &Pubkey,
-    _accounts: &[AccountInfo],
+    accounts: &[AccountInfo],
     ix: &[u8],
 ) -> ProgramResult {
+    match ix.split_first() {
+        Some((0, ix)) => verify_single_signature(ix),
+        Some((1, ix)) => verify_partials(accounts, ix),
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+fn verify_single_signature(ix: &[u8]) -> ProgramResult {
     if ix.len() < 64 + 32 {
         return Err(ProgramError::InvalidInstructionData);
     }
 
     // Parse compressed G2 pubkey
-    let pk_c = G2CompressedPoint(
-        ix[0..64].try_into().map_err(|_| ProgramError::InvalidInstructionData)?,
-    );
+    let pk_c = G2CompressedPoint::try_from(&ix[0..64])
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
 
     // Parse compressed G1 signature
-    let sig_c = G1CompressedPoint(
-        ix[64..96].try_into().map_err(|_| ProgramError::InvalidInstructionData)?,
-    );
+    let sig_c = G1CompressedPoint::try_from(&ix[64..96])
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
 
     // Remaining is message
     let msg = &ix[96..];
 
     // Decompress signature and verify against provided G2 pubkey
     let sig = G1Point::try_from(&sig_c).map_err(|_| ProgramError::InvalidInstructionData)?;
-    pk_c.verify(&sig, msg).map_err(|_| ProgramError::MissingRequiredSignature)?;
+    pk_c.verify(&sig, msg)?;
+
+    Ok(())
+}
+
+fn verify_partials(accounts: &[AccountInfo], ix: &[u8]) -> ProgramResult {
+    let [registry, partials, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    if ix.len() < 2 {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    // Parse the signer indices
+    let k = u16::from_le_bytes([ix[0], ix[1]]) as usize;
+    let (indices, msg) = ix[2..]
+        .split_at_checked(2 * k)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    let signer_indices: Vec<u16> = indices
+        .chunks_exact(2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+        .collect();
+
+    // Committee keys and partials both come straight from account data
+    let registry_data = registry.try_borrow_data()?;
+    let committee = Registry::new(&registry_data)?;
+    let blob = partials.try_borrow_data()?;
+    verify_from_partials_blob(&blob, &signer_indices, msg, &committee)?;
 
     Ok(())
 }
@@ -45,8 +84,9 @@ fn process_instruction(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use brine_bn128_bls::{G2CompressedPoint, PrivKey};
-    use mollusk_svm::Mollusk;
+    use brine_bn128_bls::utils::bls_partial_sign;
+    use brine_bn128_bls::{Fr, G2CompressedPoint, PrivKey};
+    use mollusk_svm::{result::Check, Mollusk};
     use solana_sdk::{
         account::AccountSharedData,
         instruction::{AccountMeta, Instruction},
@@ -68,8 +108,9 @@ mod tests {
         let sig_c = G1CompressedPoint::try_from(sig).expect("compress sig");
         let pk_c = G2CompressedPoint::try_from(&sk).expect("compress pk");
 
-        // Build instruction data: [pk_c | sig_c | msg]
-        let mut ix_data = Vec::with_capacity(64 + 32 + msg.len());
+        // Build instruction data: [0 | pk_c | sig_c | msg]
+        let mut ix_data = Vec::with_capacity(1 + 64 + 32 + msg.len());
+        ix_data.push(0);
         ix_data.extend_from_slice(&pk_c.0);
         ix_data.extend_from_slice(&sig_c.0);
         ix_data.extend_from_slice(msg);
@@ -87,4 +128,66 @@ mod tests {
             &[(signer, AccountSharedData::new(10_000, 0, &Pubkey::default()))],
         );
     }
+
+    #[test]
+    fn svm_verifies_partials_from_account_data() {
+        let program_id = pubkey!("B1sA1tBn128111111111111111111111111111111111");
+        let mollusk = Mollusk::new(&program_id, "target/deploy/brine_bn128_bls_test");
+
+        // A 5-member committee stored as a registry blob, and partials from members 0, 2 and 3
+        let msg = b"partials-from-account";
+        let keys: Vec<PrivKey> = (0..5).map(|_| PrivKey::from_random()).collect();
+        let registry: Vec<u8> = keys
+            .iter()
+            .flat_map(|k| G2CompressedPoint::try_from(k).expect("compress pk").0)
+            .collect();
+        let signers = [0u16, 2, 3];
+        let partials: Vec<G1Point> = signers
+            .iter()
+            .map(|&i| Fr::try_from(&keys[i as usize]).expect("scalar"))
+            .map(|sk| bls_partial_sign(&sk, msg).expect("partial"))
+            .collect();
+        let blob: Vec<u8> = partials
+            .iter()
+            .flat_map(|p| G1CompressedPoint::try_from(*p).expect("compress partial").0)
+            .collect();
+
+        // Build instruction data: [1 | k | indices | msg]
+        let mut ix_data = vec![1];
+        ix_data.extend_from_slice(&(signers.len() as u16).to_le_bytes());
+        for i in signers {
+            ix_data.extend_from_slice(&i.to_le_bytes());
+        }
+        ix_data.extend_from_slice(msg);
+
+        let registry_key = Pubkey::new_unique();
+        let partials_key = Pubkey::new_unique();
+        let account = |data: &[u8]| {
+            let mut account = AccountSharedData::new(10_000_000, data.len(), &program_id);
+            account.set_data_from_slice(data);
+            account
+        };
+        let ix = Instruction::new_with_bytes(
+            program_id,
+            &ix_data,
+            vec![
+                AccountMeta::new_readonly(registry_key, false),
+                AccountMeta::new_readonly(partials_key, false),
+            ],
+        );
+        mollusk.process_and_validate_instruction(
+            &ix,
+            &[(registry_key, account(&registry)), (partials_key, account(&blob))],
+            &[Check::success()],
+        );
+
+        // Claiming a different signer set fails with the BLSVerificationError code
+        ix_data[3..5].copy_from_slice(&1u16.to_le_bytes());
+        let ix = Instruction::new_with_bytes(program_id, &ix_data, ix.accounts.clone());
+        mollusk.process_and_validate_instruction(
+            &ix,
+            &[(registry_key, account(&registry)), (partials_key, account(&blob))],
+            &[Check::err(solana_sdk::program_error::ProgramError::Custom(0x1001))],
+        );
+    }
 }